@@ -179,6 +179,21 @@ impl AttributeOption for Vec<String> {
     }
 }
 
+impl AttributeOption for Vec<u64> {
+    fn parse(values: Values) -> Result<Self> {
+        validate(&values, &[ValueKind::List])?;
+
+        values
+            .literals
+            .into_iter()
+            .map(|lit| match lit {
+                Lit::Int(l) => l.base10_parse::<u64>(),
+                l => Err(Error::new(l.span(), "invalid integer")),
+            })
+            .collect()
+    }
+}
+
 impl AttributeOption for String {
     #[inline]
     fn parse(values: Values) -> Result<Self> {
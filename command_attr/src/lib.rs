@@ -71,6 +71,7 @@ macro_rules! match_options {
 /// | `#[min_args(min)]` </br> `#[max_args(max)]` </br> `#[num_args(min_and_max)]` | The expected length of arguments that the command must receive in order to function correctly.           | `min`, `max` and `min_and_max` are 16-bit, unsigned integers.                                                                                                                                                                    |
 /// | `#[required_permissions(perms)]`                                             | Set of permissions the user must possess.                                                                | `perms` is a comma separated list of permission names.</br> These can be found at [Discord's official documentation](https://discord.com/developers/docs/topics/permissions).                                                 |
 /// | `#[allowed_roles(roles)]`                                                    | Set of roles the user must possess.                                                                      | `roles` is a comma separated list of role names.                                                                                                                                                                                 |
+/// | `#[allowed_role_ids(ids)]`                                                   | Set of roles the user must possess, by id. Takes precedence over `allowed_roles`.                         | `ids` is a comma separated list of role ids.                                                                                                                                                                                     |
 /// | `#[help_available]` </br> `#[help_available(b)]`                             | If the command should be displayed in the help message.                                                  | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                                                                  |
 /// | `#[only_in(ctx)]`                                                            | Which environment the command can be executed in.                                                        | `ctx` is a string with the accepted values `guild`/`guilds` and `dm`/`dms` (Direct Message).                                                                                                                                     |
 /// | `#[bucket(name)]` </br> `#[bucket = name]`                                   | What bucket will impact this command.                                                                    | `name` is a string containing the bucket's name.</br> Refer to [the bucket example in the standard framework](https://docs.rs/serenity/*/serenity/framework/standard/struct.StandardFramework.html#method.bucket) for its usage. |
@@ -141,6 +142,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
                     max_args;
                     required_permissions;
                     allowed_roles;
+                    allowed_role_ids;
                     help_available;
                     only_in;
                     owners_only;
@@ -162,6 +164,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
         min_args,
         max_args,
         allowed_roles,
+        allowed_role_ids,
         required_permissions,
         help_available,
         only_in,
@@ -206,6 +209,7 @@ pub fn command(attr: TokenStream, input: TokenStream) -> TokenStream {
             min_args: #min_args,
             max_args: #max_args,
             allowed_roles: &[#(#allowed_roles),*],
+            allowed_role_ids: &[#(serenity::model::id::RoleId(#allowed_role_ids)),*],
             required_permissions: #required_permissions,
             help_available: #help_available,
             only_in: #only_in,
@@ -591,6 +595,7 @@ pub fn help(attr: TokenStream, input: TokenStream) -> TokenStream {
 /// | `#[prefixes(prefs)]`                                 | Text that must appear   before an invocation of a command of this group may occur. | `prefs` is a comma separated list of strings                                                                                                                                         |
 /// | `#[prefix(pref)]`                                    | Assign just a single prefix.                                                       | `pref` is a string                                                                                                                                                                   |
 /// | `#[allowed_roles(roles)]`                            | Set of roles the user must possess                                                 | `roles` is a comma separated list of strings containing role names                                                                                                                   |
+/// | `#[allowed_role_ids(ids)]`                           | Set of roles the user must possess, by id. Takes precedence over `allowed_roles`   | `ids` is a comma separated list of role ids                                                                                                                                          |
 /// | `#[only_in(ctx)]`                                    | Which environment the command can be executed in.                                  | `ctx` is a string with the accepted values `guild`/`guilds` and `dm`/ `dms` (Direct Message).                                                                                        |
 /// | `#[owners_only]` </br> `#[owners_only(b)]`           | If this command is exclusive to owners.                                            | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                      |
 /// | `#[owner_privilege]` </br> `#[owner_privilege(b)]`   | If owners can bypass certain options.                                              | `b` is a boolean. If no boolean is provided, the value is assumed to be `true`.                                                                                                      |
@@ -660,6 +665,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
                 owner_privilege;
                 help_available;
                 allowed_roles;
+                allowed_role_ids;
                 required_permissions;
                 checks;
                 default_command;
@@ -676,6 +682,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
         owner_privilege,
         help_available,
         allowed_roles,
+        allowed_role_ids,
         required_permissions,
         checks,
         default_command,
@@ -713,6 +720,7 @@ pub fn group(attr: TokenStream, input: TokenStream) -> TokenStream {
             owner_privilege: #owner_privilege,
             help_available: #help_available,
             allowed_roles: &[#(#allowed_roles),*],
+            allowed_role_ids: &[#(serenity::model::id::RoleId(#allowed_role_ids)),*],
             required_permissions: #required_permissions,
             checks: #checks,
             default_command: #default_command,
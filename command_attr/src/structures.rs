@@ -453,6 +453,7 @@ pub struct Options {
     pub min_args: AsOption<u16>,
     pub max_args: AsOption<u16>,
     pub allowed_roles: Vec<String>,
+    pub allowed_role_ids: Vec<u64>,
     pub required_permissions: Permissions,
     pub help_available: bool,
     pub only_in: OnlyIn,
@@ -626,6 +627,7 @@ pub struct GroupOptions {
     pub owner_privilege: bool,
     pub help_available: bool,
     pub allowed_roles: Vec<String>,
+    pub allowed_role_ids: Vec<u64>,
     pub required_permissions: Permissions,
     pub checks: Checks,
     pub default_command: AsOption<Ident>,
@@ -97,3 +97,33 @@ impl CreateBotAuthParameters {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::CreateBotAuthParameters;
+    use crate::model::oauth2::OAuth2Scope;
+    use crate::model::Permissions;
+
+    #[test]
+    fn test_scopes_are_encoded() {
+        let mut builder = CreateBotAuthParameters::default();
+        builder.client_id(1).scopes(&[OAuth2Scope::Bot, OAuth2Scope::ApplicationsCommands]);
+
+        assert_eq!(
+            builder.build(),
+            "https://discord.com/api/oauth2/authorize?client_id=1&scope=bot+applications.commands"
+        );
+    }
+
+    #[test]
+    fn test_permissions() {
+        let mut builder = CreateBotAuthParameters::default();
+        builder.client_id(1).scopes(&[OAuth2Scope::Bot]).permissions(Permissions::empty());
+
+        assert!(!builder.clone().build().contains("permissions"));
+
+        builder.permissions(Permissions::SEND_MESSAGES);
+
+        assert!(builder.build().ends_with("&permissions=2048"));
+    }
+}
@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use serde_json::Value;
 
+use crate::constants;
+use crate::internal::prelude::*;
+use crate::model::id::{RoleId, UserId};
 use crate::model::interactions::application_command::ApplicationCommandPermissionType;
+use crate::model::ModelError;
 use crate::utils;
 
 /// A builder for creating several [`ApplicationCommandPermission`].
@@ -56,6 +60,22 @@ impl CreateApplicationCommandsPermissions {
 
         self
     }
+
+    /// Checks that none of the application commands have more than 100
+    /// permissions, and that all of their permissions are complete.
+    ///
+    /// This is done automatically when the permissions are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommandPermissionAmount`] if an application
+    /// command has too many permissions.
+    ///
+    /// Returns a [`ModelError::MissingField`] with the name of the first field
+    /// that has not been set on a permission.
+    pub fn validate(&self) -> Result<()> {
+        self.0.iter().try_for_each(|command| check_permissions(command.get("permissions")))
+    }
 }
 /// A builder for creating an [`ApplicationCommandPermission`].
 ///
@@ -89,34 +109,58 @@ impl CreateApplicationCommandPermissions {
     }
 
     /// Adds permission for the application command.
+    ///
+    /// A permission for the same role or user as an existing one replaces it.
+    ///
+    /// **Note**: Incomplete permissions are accepted here, and reported by
+    /// [`Self::validate`] when the permissions are sent.
     pub fn add_permissions(
         &mut self,
         permission: CreateApplicationCommandPermissionData,
     ) -> &mut Self {
-        let new_data = utils::hashmap_to_json_map(permission.0);
         let permissions = self.0.entry("permissions").or_insert_with(|| Value::Array(Vec::new()));
 
         let permissions_array = permissions.as_array_mut().expect("Must be an array");
 
-        permissions_array.push(Value::Object(new_data));
+        insert_permission(permissions_array, permission);
 
         self
     }
 
     /// Sets permissions for the application command.
+    ///
+    /// If several permissions are for the same role or user, the last one is
+    /// kept.
     pub fn set_permissions(
         &mut self,
         permissions: Vec<CreateApplicationCommandPermissionData>,
     ) -> &mut Self {
-        let new_permissions = permissions
-            .into_iter()
-            .map(|f| Value::Object(utils::hashmap_to_json_map(f.0)))
-            .collect::<Vec<Value>>();
+        let mut new_permissions = Vec::with_capacity(permissions.len());
+
+        for permission in permissions {
+            insert_permission(&mut new_permissions, permission);
+        }
 
         self.0.insert("permissions", Value::Array(new_permissions));
 
         self
     }
+
+    /// Checks that there are no more than 100 permissions, the maximum for
+    /// an application command, and that all of them are complete.
+    ///
+    /// This is done automatically when the permissions are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommandPermissionAmount`] if there are too many
+    /// permissions.
+    ///
+    /// Returns a [`ModelError::MissingField`] with the name of the first field
+    /// that has not been set on a permission.
+    pub fn validate(&self) -> Result<()> {
+        check_permissions(self.0.get("permissions"))
+    }
 }
 
 /// A builder for creating several [`ApplicationCommandPermissionData`].
@@ -143,39 +187,64 @@ impl CreateApplicationCommandPermissionsData {
     }
 
     /// Adds a permission for the application command.
+    ///
+    /// A permission for the same role or user as an existing one replaces it.
+    ///
+    /// **Note**: Incomplete permissions are accepted here, and reported by
+    /// [`Self::validate`] when the permissions are sent.
     pub fn add_permission(
         &mut self,
         permission: CreateApplicationCommandPermissionData,
     ) -> &mut Self {
-        let new_data = utils::hashmap_to_json_map(permission.0);
         let permissions = self.0.entry("permissions").or_insert_with(|| Value::Array(Vec::new()));
 
         let permissions_array = permissions.as_array_mut().expect("Must be an array");
 
-        permissions_array.push(Value::Object(new_data));
+        insert_permission(permissions_array, permission);
 
         self
     }
 
     /// Sets permissions for the application command.
+    ///
+    /// If several permissions are for the same role or user, the last one is
+    /// kept.
     pub fn set_permissions(
         &mut self,
         permissions: Vec<CreateApplicationCommandPermissionData>,
     ) -> &mut Self {
-        let new_permissions = permissions
-            .into_iter()
-            .map(|f| Value::Object(utils::hashmap_to_json_map(f.0)))
-            .collect::<Vec<Value>>();
+        let mut new_permissions = Vec::with_capacity(permissions.len());
+
+        for permission in permissions {
+            insert_permission(&mut new_permissions, permission);
+        }
 
         self.0.insert("permissions", Value::Array(new_permissions));
 
         self
     }
+
+    /// Checks that there are no more than 100 permissions, the maximum for
+    /// an application command, and that all of them are complete.
+    ///
+    /// This is done automatically when the permissions are sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommandPermissionAmount`] if there are too many
+    /// permissions.
+    ///
+    /// Returns a [`ModelError::MissingField`] with the name of the first field
+    /// that has not been set on a permission.
+    pub fn validate(&self) -> Result<()> {
+        check_permissions(self.0.get("permissions"))
+    }
 }
 
 /// A builder for creating an [`ApplicationCommandPermissionData`].
 ///
-/// All fields are required.
+/// All fields are required. They are checked when the permission is sent, or
+/// earlier with [`Self::validate`].
 ///
 /// [`ApplicationCommandPermissionData`]: crate::model::interactions::ApplicationCommandPermissionData
 /// [`kind`]: Self::kind
@@ -199,6 +268,22 @@ impl CreateApplicationCommandPermissionData {
         self
     }
 
+    /// Sets the permission to apply to a role, setting both the Id and the
+    /// [`kind`].
+    ///
+    /// [`kind`]: Self::kind
+    pub fn role(&mut self, role_id: impl Into<RoleId>) -> &mut Self {
+        self.id(role_id.into().0).kind(ApplicationCommandPermissionType::Role)
+    }
+
+    /// Sets the permission to apply to a user, setting both the Id and the
+    /// [`kind`].
+    ///
+    /// [`kind`]: Self::kind
+    pub fn user(&mut self, user_id: impl Into<UserId>) -> &mut Self {
+        self.id(user_id.into().0).kind(ApplicationCommandPermissionType::User)
+    }
+
     /// Sets the permission for the [`ApplicationCommandPermissionData`].
     ///
     /// **Note**: Setting it to `false` will only grey the application command in the
@@ -209,4 +294,182 @@ impl CreateApplicationCommandPermissionData {
         self.0.insert("permission", Value::Bool(permission));
         self
     }
+
+    /// Checks that the [`Self::id`], [`Self::kind`] and [`Self::permission`]
+    /// have all been set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MissingField`] with the name of the first field
+    /// that has not been set.
+    pub fn validate(&self) -> Result<()> {
+        check_permission_fields(|field| self.0.contains_key(field))
+    }
+}
+
+/// Adds a permission to the list, replacing the permission for the same role
+/// or user if there is one.
+fn insert_permission(
+    permissions: &mut Vec<Value>,
+    permission: CreateApplicationCommandPermissionData,
+) {
+    let new_data = Value::Object(utils::hashmap_to_json_map(permission.0));
+
+    let existing = permissions.iter_mut().find(|existing| {
+        existing.get("id") == new_data.get("id") && existing.get("type") == new_data.get("type")
+    });
+
+    match existing {
+        Some(existing) => *existing = new_data,
+        None => permissions.push(new_data),
+    }
+}
+
+fn check_permissions(permissions: Option<&Value>) -> Result<()> {
+    if let Some(Value::Array(permissions)) = permissions {
+        if permissions.len() > constants::APPLICATION_COMMAND_PERMISSIONS_LIMIT {
+            return Err(Error::Model(ModelError::CommandPermissionAmount(permissions.len())));
+        }
+
+        for permission in permissions {
+            check_permission_fields(|field| permission.get(field).is_some())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_permission_fields(has_field: impl Fn(&str) -> bool) -> Result<()> {
+    for field in &["id", "type", "permission"] {
+        if !has_field(field) {
+            return Err(Error::Model(ModelError::MissingField(field)));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::{json, Value};
+
+    use super::{
+        CreateApplicationCommandPermissionData,
+        CreateApplicationCommandPermissionsData,
+        CreateApplicationCommandsPermissions,
+    };
+    use crate::model::id::{RoleId, UserId};
+    use crate::model::interactions::application_command::ApplicationCommandPermissionType;
+    use crate::model::ModelError;
+    use crate::utils;
+    use crate::Error;
+
+    #[test]
+    fn validate_incomplete() {
+        let mut data = CreateApplicationCommandPermissionData::default();
+        data.id(1).permission(true);
+
+        assert!(matches!(data.validate(), Err(Error::Model(ModelError::MissingField("type")))));
+    }
+
+    #[test]
+    fn complete_permission() {
+        let mut permissions = CreateApplicationCommandPermissionsData::default();
+        permissions.create_permission(|p| {
+            p.id(1).kind(ApplicationCommandPermissionType::Role).permission(true)
+        });
+
+        let built = Value::Object(utils::hashmap_to_json_map(permissions.0));
+
+        assert_eq!(
+            built,
+            json!({
+                "permissions": [
+                    {"id": "1", "type": 1, "permission": true},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn add_incomplete_permission() {
+        let mut permissions = CreateApplicationCommandPermissionsData::default();
+        permissions.create_permission(|p| p.id(1).kind(ApplicationCommandPermissionType::User));
+
+        assert!(matches!(
+            permissions.validate(),
+            Err(Error::Model(ModelError::MissingField("permission")))
+        ));
+
+        let mut commands = CreateApplicationCommandsPermissions::default();
+        commands.create_application_command(|c| {
+            c.id(1).create_permissions(|p| p.id(1).kind(ApplicationCommandPermissionType::User))
+        });
+
+        assert!(matches!(
+            commands.validate(),
+            Err(Error::Model(ModelError::MissingField("permission")))
+        ));
+    }
+
+    #[test]
+    fn role_and_user() {
+        let mut data = CreateApplicationCommandPermissionData::default();
+        data.role(RoleId(7)).permission(true);
+
+        assert_eq!(data.0.get("id"), Some(&json!("7")));
+        assert_eq!(data.0.get("type"), Some(&json!(ApplicationCommandPermissionType::Role as u8)));
+
+        data.user(UserId(8));
+
+        assert_eq!(data.0.get("id"), Some(&json!("8")));
+        assert_eq!(data.0.get("type"), Some(&json!(ApplicationCommandPermissionType::User as u8)));
+    }
+
+    #[test]
+    fn add_replaces_duplicate() {
+        let mut permissions = CreateApplicationCommandPermissionsData::default();
+        permissions
+            .create_permission(|p| p.role(RoleId(1)).permission(true))
+            .create_permission(|p| p.user(UserId(1)).permission(true))
+            .create_permission(|p| p.role(RoleId(1)).permission(false));
+
+        assert_eq!(
+            permissions.0.get("permissions"),
+            Some(&json!([
+                {"id": "1", "type": 1, "permission": false},
+                {"id": "1", "type": 2, "permission": true},
+            ]))
+        );
+
+        let mut role = CreateApplicationCommandPermissionData::default();
+        role.role(RoleId(2)).permission(true);
+        let mut denied_role = CreateApplicationCommandPermissionData::default();
+        denied_role.role(RoleId(2)).permission(false);
+        permissions.set_permissions(vec![role, denied_role]);
+
+        assert_eq!(
+            permissions.0.get("permissions"),
+            Some(&json!([{"id": "2", "type": 1, "permission": false}]))
+        );
+    }
+
+    #[test]
+    fn permission_amount() {
+        let mut permissions = CreateApplicationCommandPermissionsData::default();
+
+        for id in 0..100 {
+            permissions.create_permission(|p| p.role(RoleId(id)).permission(true));
+        }
+
+        assert!(permissions.validate().is_ok());
+
+        permissions.create_permission(|p| p.role(RoleId(100)).permission(true));
+
+        assert!(matches!(
+            permissions.validate(),
+            Err(Error::Model(ModelError::CommandPermissionAmount(101)))
+        ));
+    }
 }
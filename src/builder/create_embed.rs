@@ -17,11 +17,15 @@
 use std::collections::HashMap;
 use std::fmt::Display;
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use serde_json::{json, Value};
 
+use crate::constants;
 use crate::internal::prelude::*;
 use crate::model::channel::Embed;
+#[cfg(feature = "utils")]
+use crate::model::guild::{Guild, Member, Role};
+use crate::model::ModelError;
 use crate::utils;
 #[cfg(feature = "utils")]
 use crate::utils::Colour;
@@ -80,6 +84,41 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the colour of the left-hand side of the embed to the colour of the
+    /// `member`'s highest hoisted coloured role in the `guild`.
+    ///
+    /// Roles with a colour of `0` are skipped. If none of the member's coloured
+    /// roles are hoisted, the embed's colour is left unset.
+    #[cfg(feature = "utils")]
+    pub fn colour_from_member(&mut self, member: &Member, guild: &Guild) -> &mut Self {
+        let role = member
+            .roles
+            .iter()
+            .filter_map(|role_id| guild.roles.get(role_id))
+            .filter(|role| role.hoist && role.colour.0 != 0)
+            .max();
+
+        if let Some(role) = role {
+            self._colour(role.colour);
+        }
+
+        self
+    }
+
+    /// Set the colour of the left-hand side of the embed to the colour of the
+    /// `role`.
+    ///
+    /// If the role has no colour, i.e. a colour of `0`, the embed's colour is
+    /// left unset.
+    #[cfg(feature = "utils")]
+    pub fn colour_from_role(&mut self, role: &Role) -> &mut Self {
+        if role.colour.0 != 0 {
+            self._colour(role.colour);
+        }
+
+        self
+    }
+
     #[cfg(feature = "utils")]
     fn _colour(&mut self, colour: Colour) {
         self.0.insert("color", Value::Number(Number::from(u64::from(colour.0))));
@@ -102,9 +141,31 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the colour of the left-hand side of the embed from a hexadecimal
+    /// string, such as `"#FF0011"` or `"FF0011"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidColour`] if the string is not six
+    /// hexadecimal digits, optionally prefixed with a `#`.
+    pub fn colour_hex(&mut self, hex: &str) -> Result<&mut Self> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::Model(ModelError::InvalidColour(hex.to_string())));
+        }
+
+        let colour = u32::from_str_radix(digits, 16)
+            .map_err(|_| Error::Model(ModelError::InvalidColour(hex.to_string())))?;
+        self.0.insert("color", Value::Number(Number::from(colour)));
+
+        Ok(self)
+    }
+
     /// Set the description of the embed.
     ///
-    /// **Note**: This can't be longer than 2048 characters.
+    /// **Note**: This can't be longer than 4096 characters. See
+    /// [`Self::validate`] to check this before sending.
     #[inline]
     pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
         self.0.insert("description", Value::String(description.to_string()));
@@ -115,7 +176,9 @@ impl CreateEmbed {
     /// add to them.
     ///
     /// **Note**: Maximum amount of characters you can put is 256 in a field
-    /// name and 1024 in a field value.
+    /// name and 1024 in a field value, and an embed can have at most 25
+    /// fields. Adding more fields is allowed, but sending the embed will fail;
+    /// see [`Self::validate`] to check this before sending.
     #[inline]
     pub fn field<T, U>(&mut self, name: T, value: U, inline: bool) -> &mut Self
     where
@@ -143,6 +206,9 @@ impl CreateEmbed {
     /// Adds multiple fields at once.
     ///
     /// This is sugar to reduce the need of calling [`Self::field`] manually multiple times.
+    ///
+    /// **Note**: As with [`Self::field`], the limit of 25 fields is only
+    /// checked when the embed is sent.
     pub fn fields<T, U, It>(&mut self, fields: It) -> &mut Self
     where
         It: IntoIterator<Item = (T, U, bool)>,
@@ -156,6 +222,44 @@ impl CreateEmbed {
         self
     }
 
+    /// Adds multiple fields at once from borrowed tuples, such as the elements
+    /// of a slice.
+    ///
+    /// This is the same as [`Self::fields`], without needing to clone the
+    /// names and values first.
+    pub fn fields_ref<'a, T, U, It>(&mut self, fields: It) -> &mut Self
+    where
+        It: IntoIterator<Item = &'a (T, U, bool)>,
+        T: ToString + 'a,
+        U: ToString + 'a,
+    {
+        for (name, value, inline) in fields {
+            self._field(name.to_string(), value.to_string(), *inline);
+        }
+
+        self
+    }
+
+    /// Removes all fields of the embed.
+    pub fn clear_fields(&mut self) -> &mut Self {
+        self.0.remove("fields");
+        self
+    }
+
+    /// Removes the field at the given position, shifting the following fields
+    /// up.
+    ///
+    /// Does nothing if there is no field at that position.
+    pub fn remove_field(&mut self, index: usize) -> &mut Self {
+        if let Some(Value::Array(fields)) = self.0.get_mut("fields") {
+            if index < fields.len() {
+                fields.remove(index);
+            }
+        }
+
+        self
+    }
+
     /// Build the footer of the embed.
     ///
     /// Refer to the documentation for [`CreateEmbedFooter`] for more
@@ -195,6 +299,28 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the provider of the embed.
+    ///
+    /// **Note**: Discord ignores this for embeds sent by bots. It is kept for
+    /// round-tripping embeds received from Discord.
+    #[inline]
+    pub fn provider<N: ToString, U: ToString>(&mut self, name: N, url: U) -> &mut Self {
+        self._provider(name.to_string(), Some(url.to_string()));
+        self
+    }
+
+    fn _provider(&mut self, name: String, url: Option<String>) {
+        let mut obj = json!({
+            "name": name,
+        });
+
+        if let (Some(url), Value::Object(map)) = (url, &mut obj) {
+            map.insert("url".to_string(), Value::String(url));
+        }
+
+        self.0.insert("provider", obj);
+    }
+
     /// Set the thumbnail of the embed. This only supports HTTP(S).
     #[inline]
     pub fn thumbnail<S: ToString>(&mut self, url: S) -> &mut Self {
@@ -211,6 +337,8 @@ impl CreateEmbed {
     /// - `2004-06-08T16:04:23`
     ///
     /// This timestamp must be in ISO-8601 format. It must also be in UTC format.
+    /// String timestamps are not validated; use [`Self::try_timestamp`] to
+    /// reject malformed input before sending.
     ///
     /// You can also pass an instance of `chrono::DateTime<Utc>`,
     /// which will construct the timestamp string out of it.
@@ -313,7 +441,34 @@ impl CreateEmbed {
         self.0.insert("timestamp", Value::String(timestamp.ts));
     }
 
+    /// Set the timestamp, validating it first.
+    ///
+    /// The timestamp is parsed as ISO-8601 and normalized to RFC 3339 before
+    /// being set. Timestamps without an offset, such as `2004-06-08T16:04:23`,
+    /// are assumed to be in UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidTimestamp`] if the timestamp could not be
+    /// parsed. The embed is left unchanged in this case.
+    pub fn try_timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> Result<&mut Self> {
+        let timestamp = timestamp.into();
+
+        match normalize_timestamp(&timestamp.ts) {
+            Some(ts) => {
+                self._timestamp(Timestamp {
+                    ts,
+                });
+
+                Ok(self)
+            },
+            None => Err(Error::Model(ModelError::InvalidTimestamp(timestamp.ts))),
+        }
+    }
+
     /// Set the title of the embed.
+    ///
+    /// **Note**: This can't be longer than 256 characters.
     #[inline]
     pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
         self.0.insert("title", Value::String(title.to_string()));
@@ -327,6 +482,16 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the video of the embed.
+    ///
+    /// **Note**: Discord ignores this for embeds sent by bots. It is kept for
+    /// round-tripping embeds received from Discord.
+    #[inline]
+    pub fn video<S: ToString>(&mut self, url: S) -> &mut Self {
+        self.url_object("video", url.to_string());
+        self
+    }
+
     /// Same as calling [`Self::image`] with "attachment://filename.(jpg, png)".
     ///
     /// Note however, you have to be sure you set an attachment (with [`ChannelId::send_files`])
@@ -341,6 +506,88 @@ impl CreateEmbed {
 
         self
     }
+
+    /// Checks the embed against Discord's limits on its fields and the length
+    /// of its textual content.
+    ///
+    /// This is done automatically when the embed is sent as part of a message,
+    /// but can be used to catch an oversized embed before then.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedFieldAmount`] if the embed has more than 25
+    /// fields.
+    ///
+    /// Returns a [`ModelError::EmbedValueTooLong`] if the title, description,
+    /// author name, footer text, or a field's name or value is too long,
+    /// containing the name of the value and the number of unicode code points
+    /// over its limit.
+    pub fn validate(&self) -> Result<()> {
+        check_limits(|key| self.0.get(key))
+    }
+
+    /// Combines another embed builder into this one.
+    ///
+    /// Every value set on `other` - such as its title, description, colour,
+    /// author or footer - replaces the one set on this embed, while its fields
+    /// are added after this embed's fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedFieldAmount`] with the combined number of
+    /// fields if it exceeds the maximum of 25, leaving this embed unchanged.
+    pub fn merge(&mut self, other: CreateEmbed) -> Result<&mut Self> {
+        let amount = |embed: &CreateEmbed| match embed.0.get("fields") {
+            Some(Value::Array(fields)) => fields.len(),
+            _ => 0,
+        };
+        let total = amount(self) + amount(&other);
+
+        if total > constants::EMBED_MAX_FIELDS {
+            return Err(Error::Model(ModelError::EmbedFieldAmount(total)));
+        }
+
+        for (key, value) in other.0 {
+            match (key, value) {
+                ("fields", Value::Array(fields)) => {
+                    let entry = self.0.entry("fields").or_insert_with(|| Value::Array(vec![]));
+
+                    if let Value::Array(ref mut inner) = *entry {
+                        inner.extend(fields);
+                    }
+                },
+                (key, value) => {
+                    self.0.insert(key, value);
+                },
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Shortens the title, description and field values of the embed to
+    /// Discord's limits, replacing the end of any truncated text with an
+    /// ellipsis (`…`).
+    ///
+    /// Text is always cut between unicode code points, so this is safe to use
+    /// on arbitrary user content.
+    pub fn truncate(&mut self) -> &mut Self {
+        if let Some(title) = self.0.get_mut("title") {
+            truncate_value(title, constants::EMBED_TITLE_LIMIT);
+        }
+
+        if let Some(description) = self.0.get_mut("description") {
+            truncate_value(description, constants::EMBED_DESCRIPTION_LIMIT);
+        }
+
+        if let Some(Value::Array(fields)) = self.0.get_mut("fields") {
+            for value in fields.iter_mut().filter_map(|field| field.get_mut("value")) {
+                truncate_value(value, constants::EMBED_FIELD_VALUE_LIMIT);
+            }
+        }
+
+        self
+    }
 }
 
 impl Default for CreateEmbed {
@@ -363,14 +610,14 @@ impl From<Embed> for CreateEmbed {
 
         if let Some(author) = embed.author {
             b.author(move |a| {
-                a.name(&author.name);
+                a.name(author.name);
 
                 if let Some(icon_url) = author.icon_url {
-                    a.icon_url(&icon_url);
+                    a.icon_url(icon_url);
                 }
 
                 if let Some(url) = author.url {
-                    a.url(&url);
+                    a.url(url);
                 }
 
                 a
@@ -378,7 +625,7 @@ impl From<Embed> for CreateEmbed {
         }
 
         if let Some(description) = embed.description {
-            b.description(&description);
+            b.description(description);
         }
 
         for field in embed.fields {
@@ -386,31 +633,39 @@ impl From<Embed> for CreateEmbed {
         }
 
         if let Some(image) = embed.image {
-            b.image(&image.url);
+            b.image(image.url);
         }
 
         if let Some(timestamp) = embed.timestamp {
             b.timestamp(timestamp);
         }
 
+        if let Some(provider) = embed.provider {
+            b._provider(provider.name, provider.url);
+        }
+
         if let Some(thumbnail) = embed.thumbnail {
-            b.thumbnail(&thumbnail.url);
+            b.thumbnail(thumbnail.url);
+        }
+
+        if let Some(video) = embed.video {
+            b.video(video.url);
         }
 
         if let Some(url) = embed.url {
-            b.url(&url);
+            b.url(url);
         }
 
         if let Some(title) = embed.title {
-            b.title(&title);
+            b.title(title);
         }
 
         if let Some(footer) = embed.footer {
             b.footer(move |f| {
-                f.text(&footer.text);
+                f.text(footer.text);
 
                 if let Some(icon_url) = footer.icon_url {
-                    f.icon_url(&icon_url);
+                    f.icon_url(icon_url);
                 }
 
                 f
@@ -505,14 +760,87 @@ where
     }
 }
 
+/// Checks a single embed, given as a lookup of its top-level keys, against
+/// Discord's per-embed limits.
+pub(crate) fn check_limits<'a, F>(get: F) -> Result<()>
+where
+    F: Fn(&'static str) -> Option<&'a Value>,
+{
+    fn check(name: &'static str, value: Option<&Value>, limit: usize) -> Result<()> {
+        if let Some(Value::String(value)) = value {
+            let length = value.chars().count();
+
+            if length > limit {
+                return Err(Error::Model(ModelError::EmbedValueTooLong(name, length - limit)));
+            }
+        }
+
+        Ok(())
+    }
+
+    check("title", get("title"), constants::EMBED_TITLE_LIMIT)?;
+    check("description", get("description"), constants::EMBED_DESCRIPTION_LIMIT)?;
+
+    if let Some(author) = get("author") {
+        check("author name", author.get("name"), constants::EMBED_AUTHOR_NAME_LIMIT)?;
+    }
+
+    if let Some(footer) = get("footer") {
+        check("footer text", footer.get("text"), constants::EMBED_FOOTER_TEXT_LIMIT)?;
+    }
+
+    if let Some(Value::Array(fields)) = get("fields") {
+        if fields.len() > constants::EMBED_MAX_FIELDS {
+            return Err(Error::Model(ModelError::EmbedFieldAmount(fields.len())));
+        }
+
+        for field in fields {
+            check("field name", field.get("name"), constants::EMBED_FIELD_NAME_LIMIT)?;
+            check("field value", field.get("value"), constants::EMBED_FIELD_VALUE_LIMIT)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn truncate_value(value: &mut Value, limit: usize) {
+    if let Value::String(text) = value {
+        if let Some((index, _)) = text.char_indices().nth(limit) {
+            let end = text[..index].char_indices().last().map_or(0, |(index, _)| index);
+
+            text.truncate(end);
+            text.push('\u{2026}');
+        }
+    }
+}
+
+fn normalize_timestamp(ts: &str) -> Option<String> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(ts) {
+        return Some(dt.to_rfc3339());
+    }
+
+    ts.parse::<NaiveDateTime>().ok().map(|naive| Utc.from_utc_datetime(&naive).to_rfc3339())
+}
+
 #[cfg(test)]
 mod test {
     use serde_json::{json, Value};
 
     use super::CreateEmbed;
     use crate::{
-        model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo},
+        model::channel::{
+            Embed,
+            EmbedField,
+            EmbedFooter,
+            EmbedImage,
+            EmbedProvider,
+            EmbedVideo,
+        },
+        model::fixtures,
+        model::prelude::*,
+        model::ModelError,
         utils::{self, Colour},
+        Error,
     };
 
     #[test]
@@ -545,7 +873,10 @@ mod test {
                 width: 224,
             }),
             kind: "rich".to_string(),
-            provider: None,
+            provider: Some(EmbedProvider {
+                name: "hakase provider".to_string(),
+                url: Some("https://example.com".to_string()),
+            }),
             thumbnail: None,
             timestamp: None,
             title: Some("hakase".to_string()),
@@ -590,9 +921,250 @@ mod test {
             "footer": {
                 "text": "This is a hakase footer",
                 "icon_url": "https://i.imgur.com/XfWpfCV.gif",
-            }
+            },
+            "provider": {
+                "name": "hakase provider",
+                "url": "https://example.com",
+            },
+            "video": {
+                "url": "https://i.imgur.com/XfWpfCV.mp4",
+            },
         });
 
         assert_eq!(built, obj);
     }
+
+    fn coloured_role(id: u64, position: i64, colour: u32, hoist: bool) -> Role {
+        let mut role = fixtures::role(id, position);
+        role.colour = Colour(colour);
+        role.hoist = hoist;
+
+        role
+    }
+
+    #[test]
+    fn test_colour_from_member() {
+        let guild = fixtures::guild(
+            vec![
+                coloured_role(2, 1, 0x00FF00, false),
+                coloured_role(3, 5, 0xFF0000, false),
+                coloured_role(4, 9, 0, true),
+                coloured_role(5, 12, 0x0000FF, false),
+                coloured_role(6, 3, 0xFFFF00, true),
+            ],
+            vec![],
+        );
+
+        // Role 6 is the only hoisted coloured role, so it wins over the higher
+        // role 3. The highest role is uncoloured and role 5 isn't held by the
+        // member.
+        let member = fixtures::member(2, &[2, 3, 4, 6]);
+        let mut builder = CreateEmbed::default();
+        builder.colour_from_member(&member, &guild);
+
+        assert_eq!(builder.0.get("color"), Some(&json!(0xFFFF00)));
+
+        // Coloured roles that aren't hoisted don't apply.
+        let member = fixtures::member(2, &[2, 3, 5]);
+        let mut builder = CreateEmbed::default();
+        builder.colour_from_member(&member, &guild);
+
+        assert_eq!(builder.0.get("color"), None);
+
+        // Neither does a hoisted role without a colour.
+        let member = fixtures::member(2, &[4]);
+        let mut builder = CreateEmbed::default();
+        builder.colour_from_member(&member, &guild);
+
+        assert_eq!(builder.0.get("color"), None);
+    }
+
+    #[test]
+    fn test_colour_from_role() {
+        let mut builder = CreateEmbed::default();
+        builder.colour_from_role(&coloured_role(2, 1, 0x00FF00, false));
+
+        assert_eq!(builder.0.get("color"), Some(&json!(0x00FF00)));
+
+        let mut builder = CreateEmbed::default();
+        builder.colour_from_role(&fixtures::role(3, 5));
+
+        assert_eq!(builder.0.get("color"), None);
+    }
+
+    #[test]
+    fn test_fields_ref() {
+        let fields =
+            [("a".to_string(), "b".to_string(), true), ("c".to_string(), "d".to_string(), false)];
+
+        let mut builder = CreateEmbed::default();
+        builder.fields_ref(&fields);
+
+        assert_eq!(
+            builder.0["fields"],
+            json!([
+                {"name": "a", "value": "b", "inline": true},
+                {"name": "c", "value": "d", "inline": false},
+            ])
+        );
+    }
+
+    #[test]
+    fn test_clear_fields() {
+        let mut builder = CreateEmbed::default();
+        builder.title("title");
+        builder.fields(vec![("a", "1", false), ("b", "2", false), ("c", "3", false)]);
+        builder.clear_fields();
+
+        assert_eq!(builder.0.get("fields"), None);
+        assert_eq!(builder.0["title"], "title");
+    }
+
+    #[test]
+    fn test_remove_field() {
+        let mut builder = CreateEmbed::default();
+        builder.title("title");
+        builder.fields(vec![("a", "1", false), ("b", "2", false), ("c", "3", false)]);
+        builder.remove_field(1).remove_field(5);
+
+        let names = builder.0["fields"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["a", "c"]);
+        assert_eq!(builder.0["title"], "title");
+    }
+
+    #[test]
+    fn test_colour_hex() {
+        let mut builder = CreateEmbed::default();
+
+        assert!(builder.colour_hex("#FF0011").is_ok());
+        assert_eq!(builder.0["color"], 0xff0011);
+
+        assert!(builder.colour_hex("00ff00").is_ok());
+        assert_eq!(builder.0["color"], 0x00ff00);
+
+        assert!(matches!(
+            builder.colour_hex("nope"),
+            Err(Error::Model(ModelError::InvalidColour(colour))) if colour == "nope"
+        ));
+        assert!(builder.colour_hex("#+12345").is_err());
+        assert_eq!(builder.0["color"], 0x00ff00);
+    }
+
+    #[test]
+    fn test_try_timestamp() {
+        let mut builder = CreateEmbed::default();
+
+        assert!(builder.try_timestamp("2017-13-99").is_err());
+        assert_eq!(builder.0.get("timestamp"), None);
+
+        assert!(builder.try_timestamp("2004-06-08T16:04:23").is_ok());
+        assert_eq!(
+            builder.0.get("timestamp"),
+            Some(&Value::String("2004-06-08T16:04:23+00:00".to_string()))
+        );
+
+        assert!(builder.try_timestamp("2017-01-03T23:00:00.000+01:00").is_ok());
+        assert_eq!(
+            builder.0.get("timestamp"),
+            Some(&Value::String("2017-01-03T23:00:00+01:00".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_validate_field_amount() {
+        let mut builder = CreateEmbed::default();
+
+        for i in 0..25 {
+            builder.field(i, i, false);
+        }
+
+        assert!(builder.validate().is_ok());
+
+        // Fields past the limit are still added, and only rejected by the
+        // validation done when sending the embed.
+        builder.field(25, 25, false);
+        assert_eq!(builder.0["fields"].as_array().unwrap().len(), 26);
+
+        assert!(matches!(builder.validate(), Err(Error::Model(ModelError::EmbedFieldAmount(26)))));
+    }
+
+    #[test]
+    fn test_validate_description_length() {
+        let mut builder = CreateEmbed::default();
+        builder.description("a".repeat(4096));
+
+        assert!(builder.validate().is_ok());
+
+        builder.description("a".repeat(4097));
+
+        assert!(matches!(
+            builder.validate(),
+            Err(Error::Model(ModelError::EmbedValueTooLong("description", 1)))
+        ));
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut builder = CreateEmbed::default();
+        builder.title("\u{e9}".repeat(300));
+        builder.description("a".repeat(5000));
+        builder.field("name", "\u{1f980}".repeat(1025), false);
+        builder.truncate();
+
+        let title = builder.0["title"].as_str().unwrap();
+        assert_eq!(title.chars().count(), 256);
+        assert!(title.ends_with("\u{e9}\u{2026}"));
+
+        let description = builder.0["description"].as_str().unwrap();
+        assert_eq!(description.chars().count(), 4096);
+        assert!(description.ends_with("a\u{2026}"));
+
+        let value = builder.0["fields"][0]["value"].as_str().unwrap();
+        assert_eq!(value.chars().count(), 1024);
+        assert!(value.ends_with("\u{1f980}\u{2026}"));
+
+        assert!(builder.validate().is_ok());
+
+        // Text that already fits is left alone.
+        builder.description("a".repeat(4096));
+        builder.truncate();
+
+        assert_eq!(builder.0["description"], Value::String("a".repeat(4096)));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut footer = CreateEmbed::default();
+        footer.footer(|f| f.text("footer")).colour(0xff0000);
+
+        let mut body = CreateEmbed::default();
+        body.title("title").description("description").field("a", "b", false).colour(0x00ff00);
+        body.merge(footer).unwrap();
+
+        assert_eq!(body.0["title"], "title");
+        assert_eq!(body.0["description"], "description");
+        assert_eq!(body.0["footer"]["text"], "footer");
+        assert_eq!(body.0["color"], 0xff0000);
+        assert_eq!(body.0["fields"].as_array().unwrap().len(), 1);
+
+        let mut fields = CreateEmbed::default();
+        fields.fields((0..24).map(|i| (i, i, false))).title("fields");
+        body.merge(fields.clone()).unwrap();
+
+        assert_eq!(body.0["title"], "fields");
+        let merged = body.0["fields"].as_array().unwrap();
+        assert_eq!(merged.len(), 25);
+        assert_eq!(merged[0]["name"], "a");
+
+        // Merging past the maximum amount of fields leaves the embed alone.
+        let result = body.merge(fields);
+        assert!(matches!(result, Err(Error::Model(ModelError::EmbedFieldAmount(49)))));
+        assert_eq!(body.0["fields"].as_array().unwrap().len(), 25);
+    }
 }
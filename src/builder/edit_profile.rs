@@ -55,6 +55,20 @@ impl EditProfile {
         self
     }
 
+    /// Sets the banner of the current user. [`None`] can be passed to remove a
+    /// banner.
+    ///
+    /// A base64-encoded string is accepted as the banner content, such as one
+    /// returned by [`utils::read_image`].
+    ///
+    /// [`utils::read_image`]: crate::utils::read_image
+    pub fn banner(&mut self, banner: Option<&str>) -> &mut Self {
+        let banner = banner.map_or(Value::Null, |x| Value::String(x.to_string()));
+
+        self.0.insert("banner", banner);
+        self
+    }
+
     /// Modifies the current user's email address.
     ///
     /// Note that when modifying the email address, the current password must
@@ -102,3 +116,21 @@ impl EditProfile {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::EditProfile;
+    use crate::internal::prelude::*;
+
+    #[test]
+    fn test_banner() {
+        let mut builder = EditProfile::default();
+        builder.banner(Some("data:image/png;base64,iVBORw0KGgo="));
+
+        let banner = builder.0.get("banner").and_then(Value::as_str).expect("banner is set");
+        assert!(banner.starts_with("data:image/png;base64,"));
+
+        builder.banner(None);
+        assert_eq!(builder.0.get("banner"), Some(&Value::Null));
+    }
+}
@@ -6,7 +6,7 @@
 //! by a builder.
 
 mod create_channel;
-mod create_embed;
+pub(crate) mod create_embed;
 
 #[cfg(feature = "unstable_discord_api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -173,6 +173,13 @@ pub struct Cache {
     /// inserted into the cache. When a maximum number of messages are in a
     /// channel's cache, we can pop the front and remove that ID from the cache.
     pub(crate) message_queue: RwLock<HashMap<ChannelId, VecDeque<MessageId>>>,
+    /// The Ids of each guild's roles by their name, filled in as the standard
+    /// framework resolves role names.
+    ///
+    /// A guild's entry is removed whenever its roles are created, updated or
+    /// deleted, or when the guild itself is created, updated or deleted.
+    #[cfg(feature = "framework")]
+    pub(crate) role_ids_by_name: RwLock<HashMap<GuildId, HashMap<String, Vec<RoleId>>>>,
     /// The settings for the cache.
     settings: RwLock<Settings>,
 }
@@ -999,6 +1006,8 @@ impl Default for Cache {
             user: RwLock::new(CurrentUser::default()),
             users: RwLock::new(HashMap::default()),
             message_queue: RwLock::new(HashMap::default()),
+            #[cfg(feature = "framework")]
+            role_ids_by_name: RwLock::new(HashMap::default()),
         }
     }
 }
@@ -1038,6 +1047,9 @@ mod test {
                     discriminator: 1,
                     name: "user 1".to_owned(),
                     public_flags: None,
+                    banner: None,
+                    accent_colour: None,
+                    avatar_decoration: None,
                 },
                 channel_id: ChannelId(2),
                 guild_id: Some(GuildId(1)),
@@ -1203,4 +1215,38 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.read().await.contains_key(&ChannelId(2)));
     }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[tokio::test]
+    #[allow(clippy::unwrap_used)]
+    async fn test_cache_interaction_member() {
+        use crate::model::fixtures;
+
+        let cache = Cache::default();
+        let guild = fixtures::guild(vec![], vec![fixtures::member(7, &[3])]);
+        cache.guilds.write().await.insert(guild.id, guild);
+
+        // The member invoking an interaction replaces the cached one, with their
+        // current roles.
+        let mut event = InteractionCreateEvent {
+            interaction: serde_json::from_value(serde_json::json!({
+                "id": "5",
+                "application_id": "1",
+                "type": 2,
+                "data": {"id": "6", "name": "ping"},
+                "guild_id": "1",
+                "channel_id": "2",
+                "member": fixtures::member(7, &[3, 4]),
+                "token": "token",
+                "version": 1,
+            }))
+            .unwrap(),
+        };
+        cache.update(&mut event).await;
+
+        assert_eq!(cache.member(GuildId(1), UserId(7)).await.unwrap().roles, vec![
+            RoleId(3),
+            RoleId(4)
+        ]);
+    }
 }
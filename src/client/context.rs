@@ -8,6 +8,8 @@ use typemap_rev::TypeMap;
 pub use crate::cache::Cache;
 #[cfg(feature = "gateway")]
 use crate::client::bridge::gateway::ShardMessenger;
+#[cfg(all(feature = "standard_framework", feature = "unstable_discord_api"))]
+use crate::framework::standard::ApplicationCommandInvocation;
 #[cfg(all(feature = "unstable_discord_api", feature = "collector"))]
 use crate::collector::ComponentInteractionFilter;
 #[cfg(feature = "collector")]
@@ -45,6 +47,10 @@ pub struct Context {
     pub http: Arc<Http>,
     #[cfg(feature = "cache")]
     pub cache: Arc<Cache>,
+    /// The application command the standard framework is running a command
+    /// for, if any.
+    #[cfg(all(feature = "standard_framework", feature = "unstable_discord_api"))]
+    pub(crate) application_command: Option<Arc<ApplicationCommandInvocation>>,
 }
 
 impl Context {
@@ -63,6 +69,8 @@ impl Context {
             data,
             http,
             cache,
+            #[cfg(all(feature = "standard_framework", feature = "unstable_discord_api"))]
+            application_command: None,
         }
     }
 
@@ -72,6 +80,8 @@ impl Context {
             shard_id,
             data,
             http,
+            #[cfg(all(feature = "standard_framework", feature = "unstable_discord_api"))]
+            application_command: None,
         }
     }
 
@@ -88,6 +98,8 @@ impl Context {
             shard_id,
             data,
             http,
+            #[cfg(all(feature = "standard_framework", feature = "unstable_discord_api"))]
+            application_command: None,
         }
     }
 
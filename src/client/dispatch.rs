@@ -123,6 +123,10 @@ impl DispatchEvent {
             },
             // Already handled by the framework check macro
             Self::Model(Event::MessageCreate(_)) => {},
+            #[cfg(feature = "unstable_discord_api")]
+            Self::Model(Event::InteractionCreate(ref mut event)) => {
+                update(cache_and_http, event).await;
+            },
             Self::Model(Event::MessageUpdate(ref mut event)) => {
                 update(cache_and_http, event).await;
             },
@@ -163,8 +167,31 @@ pub(crate) fn dispatch<'rec>(
             (None, None) => {
                 event.update(&cache_and_http).await;
 
-                if let DispatchEvent::Model(Event::MessageCreate(event)) = event {
-                    #[cfg(feature = "framework")]
+                match event {
+                    DispatchEvent::Model(Event::MessageCreate(event)) => {
+                        #[cfg(feature = "framework")]
+                        {
+                            #[cfg(not(feature = "cache"))]
+                            let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                            #[cfg(feature = "cache")]
+                            let context = context(
+                                data,
+                                runner_tx,
+                                shard_id,
+                                &cache_and_http.http,
+                                &cache_and_http.cache,
+                            );
+
+                            let framework = Arc::clone(framework);
+
+                            tokio::spawn(async move {
+                                framework.dispatch(context, event.message).await;
+                            });
+                        }
+                    },
+                    #[cfg(all(feature = "framework", feature = "unstable_discord_api"))]
+                    DispatchEvent::Model(Event::InteractionCreate(event))
+                        if framework.handles_interaction(&event.interaction) =>
                     {
                         #[cfg(not(feature = "cache"))]
                         let context = context(data, runner_tx, shard_id, &cache_and_http.http);
@@ -180,9 +207,10 @@ pub(crate) fn dispatch<'rec>(
                         let framework = Arc::clone(framework);
 
                         tokio::spawn(async move {
-                            framework.dispatch(context, event.message).await;
+                            framework.interaction_dispatch(context, event.interaction).await;
                         });
-                    }
+                    },
+                    _ => {},
                 }
             },
             (Some(ref h), None) => match event {
@@ -217,6 +245,31 @@ pub(crate) fn dispatch<'rec>(
                         });
                     }
                 },
+                // Leave the interaction to the framework alone, so that only
+                // one of them responds to it.
+                #[cfg(all(feature = "framework", feature = "unstable_discord_api"))]
+                DispatchEvent::Model(Event::InteractionCreate(mut event))
+                    if framework.handles_interaction(&event.interaction) =>
+                {
+                    update(&cache_and_http, &mut event).await;
+
+                    #[cfg(not(feature = "cache"))]
+                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    #[cfg(feature = "cache")]
+                    let context = context(
+                        data,
+                        runner_tx,
+                        shard_id,
+                        &cache_and_http.http,
+                        &cache_and_http.cache,
+                    );
+
+                    let framework = Arc::clone(framework);
+
+                    tokio::spawn(async move {
+                        framework.interaction_dispatch(context, event.interaction).await;
+                    });
+                },
                 other => {
                     handle_event(other, data, h, runner_tx, shard_id, cache_and_http).await;
                 },
@@ -257,6 +310,22 @@ pub(crate) fn dispatch<'rec>(
                                 framework.dispatch(context, message).await;
                             });
                         } else {
+                            #[cfg(feature = "unstable_discord_api")]
+                            if let Event::InteractionCreate(ref interaction_event) = event {
+                                if framework.handles_interaction(&interaction_event.interaction) {
+                                    let interaction = interaction_event.interaction.clone();
+                                    event_handler.raw_event(context.clone(), event).await;
+
+                                    let framework = Arc::clone(framework);
+
+                                    tokio::spawn(async move {
+                                        framework.interaction_dispatch(context, interaction).await;
+                                    });
+
+                                    return;
+                                }
+                            }
+
                             // Avoid cloning if there will be no framework dispatch.
                             event_handler.raw_event(context, event).await;
                         }
@@ -295,6 +364,18 @@ pub(crate) fn dispatch<'rec>(
                             });
                         }
                     },
+                    #[cfg(all(feature = "framework", feature = "unstable_discord_api"))]
+                    DispatchEvent::Model(Event::InteractionCreate(mut event))
+                        if framework.handles_interaction(&event.interaction) =>
+                    {
+                        update(&cache_and_http, &mut event).await;
+
+                        let framework = Arc::clone(framework);
+
+                        tokio::spawn(async move {
+                            framework.interaction_dispatch(context, event.interaction).await;
+                        });
+                    },
                     other => {
                         handle_event(other, data, handler, runner_tx, shard_id, cache_and_http)
                             .await
@@ -753,7 +834,8 @@ async fn handle_event(
             });
         },
         #[cfg(feature = "unstable_discord_api")]
-        DispatchEvent::Model(Event::InteractionCreate(event)) => {
+        DispatchEvent::Model(Event::InteractionCreate(mut event)) => {
+            update(&cache_and_http, &mut event).await;
             let event_handler = Arc::clone(event_handler);
 
             tokio::spawn(async move {
@@ -39,7 +39,7 @@ use std::{
 
 use futures::future::BoxFuture;
 use tokio::sync::{Mutex, RwLock};
-use tracing::{debug, error, info, instrument};
+use tracing::{debug, error, info, instrument, warn};
 use typemap_rev::{TypeMap, TypeMapKey};
 
 #[cfg(feature = "gateway")]
@@ -246,6 +246,11 @@ impl<'a> ClientBuilder<'a> {
     /// *Info*:
     /// If a reference to the voice_manager is required for manual dispatch,
     /// use the [`Self::voice_manager_arc`]-method instead.
+    ///
+    /// **Note**: Voice connections require the [`GatewayIntents::GUILD_VOICE_STATES`]
+    /// intent; a warning is logged when the client starts without it.
+    ///
+    /// [`GatewayIntents::GUILD_VOICE_STATES`]: crate::client::bridge::gateway::GatewayIntents::GUILD_VOICE_STATES
     #[cfg(feature = "voice")]
     pub fn voice_manager<V>(mut self, voice_manager: V) -> Self
     where
@@ -345,6 +350,14 @@ impl<'a> Future for ClientBuilder<'a> {
             #[cfg(feature = "voice")]
             let voice_manager = self.voice_manager.take();
 
+            #[cfg(feature = "voice")]
+            if voice_manager.is_some() && !intents.guild_voice_states() {
+                warn!(
+                    "A voice manager was provided without the `GUILD_VOICE_STATES` intent; \
+                    voice connections will not complete."
+                );
+            }
+
             let cache_and_http = Arc::new(CacheAndHttp {
                 #[cfg(feature = "cache")]
                 cache: Arc::new(Cache::default()),
@@ -1020,3 +1033,110 @@ pub fn parse_token(token: impl AsRef<str>) -> Option<TokenComponents> {
         creation_time: timestamp,
     })
 }
+
+#[cfg(all(test, feature = "voice", feature = "standard_framework"))]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use futures::channel::mpsc::UnboundedSender as Sender;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber};
+
+    use super::bridge::gateway::GatewayIntents;
+    use super::bridge::voice::VoiceGatewayManager;
+    use super::ClientBuilder;
+    use crate::framework::StandardFramework;
+    use crate::gateway::InterMessage;
+    use crate::http::mock;
+    use crate::model::id::{GuildId, UserId};
+    use crate::model::voice::VoiceState;
+
+    struct Voice;
+
+    #[async_trait]
+    impl VoiceGatewayManager for Voice {
+        async fn initialise(&self, _: u64, _: UserId) {}
+
+        async fn register_shard(&self, _: u64, _: Sender<InterMessage>) {}
+
+        async fn deregister_shard(&self, _: u64) {}
+
+        async fn server_update(&self, _: GuildId, _: &Option<String>, _: &str) {}
+
+        async fn state_update(&self, _: GuildId, _: &VoiceState) {}
+    }
+
+    /// Records the messages of the warnings logged while it is the default
+    /// subscriber.
+    #[derive(Clone, Default)]
+    struct Warnings(Arc<Mutex<Vec<String>>>);
+
+    impl Subscriber for Warnings {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            *metadata.level() == Level::WARN
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            struct Message<'a>(&'a mut Vec<String>);
+
+            impl Visit for Message<'_> {
+                fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                    if field.name() == "message" {
+                        self.0.push(format!("{:?}", value));
+                    }
+                }
+            }
+
+            event.record(&mut Message(&mut self.0.lock().unwrap()));
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    async fn build(intents: GatewayIntents) -> (GatewayIntents, Vec<String>) {
+        let warnings = Warnings::default();
+        let _guard = tracing::subscriber::set_default(warnings.clone());
+
+        let gateway = r#"{"url": "wss://gateway.discord.gg"}"#.to_string();
+        let (proxy, server) = mock::serve(vec![(200, gateway)]);
+        let mut builder = ClientBuilder::new_with_http(mock::http(proxy).await)
+            .framework(StandardFramework::new())
+            .voice_manager(Voice)
+            .intents(intents);
+
+        (&mut builder).await.unwrap();
+        assert_eq!(server.join().unwrap(), ["GET /api/v9/gateway HTTP/1.1"]);
+
+        let messages = warnings.0.lock().unwrap().clone();
+        (builder.intents, messages)
+    }
+
+    #[tokio::test]
+    async fn voice_manager_without_voice_states_warns() {
+        let intents = GatewayIntents::GUILDS | GatewayIntents::GUILD_MESSAGES;
+        let (built, warnings) = build(intents).await;
+
+        assert_eq!(built, intents);
+        assert!(warnings.iter().any(|w| w.contains("without the `GUILD_VOICE_STATES` intent")));
+
+        let intents = intents | GatewayIntents::GUILD_VOICE_STATES;
+        let (built, warnings) = build(intents).await;
+
+        assert_eq!(built, intents);
+        assert!(!warnings.iter().any(|w| w.contains("GUILD_VOICE_STATES")));
+    }
+}
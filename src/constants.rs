@@ -1,11 +1,40 @@
 //! A set of constants used by the library.
 
+/// The first second of 2015, in milliseconds since the Unix epoch, from which
+/// the timestamps of Discord's snowflakes are counted.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: usize = 6000;
 
 /// The maximum number of embeds in a message.
 pub const EMBED_MAX_COUNT: usize = 10;
 
+/// The maximum number of fields in an embed.
+pub const EMBED_MAX_FIELDS: usize = 25;
+
+/// The maximum unicode code points allowed within an embed's title.
+pub const EMBED_TITLE_LIMIT: usize = 256;
+
+/// The maximum unicode code points allowed within an embed's description.
+pub const EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// The maximum unicode code points allowed within an embed field's name.
+pub const EMBED_FIELD_NAME_LIMIT: usize = 256;
+
+/// The maximum unicode code points allowed within an embed field's value.
+pub const EMBED_FIELD_VALUE_LIMIT: usize = 1024;
+
+/// The maximum unicode code points allowed within an embed footer's text.
+pub const EMBED_FOOTER_TEXT_LIMIT: usize = 2048;
+
+/// The maximum unicode code points allowed within an embed author's name.
+pub const EMBED_AUTHOR_NAME_LIMIT: usize = 256;
+
+/// The maximum number of permission overwrites for a single application
+/// command.
+pub const APPLICATION_COMMAND_PERMISSIONS_LIMIT: usize = 100;
+
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 9;
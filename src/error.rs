@@ -1,14 +1,14 @@
 use std::{
     error::Error as StdError,
     fmt::{self, Display, Error as FormatError},
-    io::Error as IoError,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
     num::ParseIntError,
 };
 
 #[cfg(feature = "gateway")]
 use async_tungstenite::tungstenite::error::Error as TungsteniteError;
 #[cfg(feature = "http")]
-use reqwest::{header::InvalidHeaderValue, Error as ReqwestError};
+use reqwest::{header::InvalidHeaderValue, Error as ReqwestError, StatusCode};
 use serde_json::Error as JsonError;
 use tracing::instrument;
 
@@ -109,6 +109,45 @@ pub enum Error {
     Tungstenite(TungsteniteError),
 }
 
+impl Error {
+    /// Whether the error is likely to be transient, so that retrying the
+    /// operation that failed may succeed.
+    ///
+    /// This is conservative: only network timeouts and dropped connections,
+    /// and ratelimit or server errors returned by Discord, are considered
+    /// retryable. Everything else, such as [`Self::Decode`], [`Self::Model`] or
+    /// [`Self::ExceededLimit`], is not.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Io(inner) => is_retryable_io(inner),
+            #[cfg(feature = "http")]
+            Error::Http(inner) => match &**inner {
+                HttpError::Request(inner) => inner.is_timeout() || inner.is_connect(),
+                HttpError::UnsuccessfulRequest(response) => {
+                    response.status_code.is_server_error()
+                        || response.status_code == StatusCode::TOO_MANY_REQUESTS
+                },
+                _ => false,
+            },
+            #[cfg(feature = "gateway")]
+            Error::Tungstenite(TungsteniteError::Io(inner)) => is_retryable_io(inner),
+            _ => false,
+        }
+    }
+}
+
+fn is_retryable_io(error: &IoError) -> bool {
+    matches!(
+        error.kind(),
+        IoErrorKind::TimedOut
+            | IoErrorKind::Interrupted
+            | IoErrorKind::ConnectionReset
+            | IoErrorKind::ConnectionAborted
+            | IoErrorKind::BrokenPipe
+            | IoErrorKind::UnexpectedEof
+    )
+}
+
 impl From<FormatError> for Error {
     fn from(e: FormatError) -> Error {
         Error::Format(e)
@@ -238,3 +277,61 @@ impl StdError for Error {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::error::Error as StdError;
+    use std::io::{Error as IoError, ErrorKind as IoErrorKind};
+
+    use super::Error;
+    use crate::model::ModelError;
+
+    #[test]
+    fn json_source() {
+        let inner = serde_json::from_str::<u64>("not json").unwrap_err();
+        let message = inner.to_string();
+        let error = Error::from(inner);
+
+        let source = error.source().expect("Json errors have a source");
+
+        assert!(source.is::<serde_json::Error>());
+        assert_eq!(source.to_string(), message);
+        assert!(source.source().is_none());
+    }
+
+    #[test]
+    fn is_retryable() {
+        assert!(Error::Io(IoError::from(IoErrorKind::TimedOut)).is_retryable());
+        assert!(Error::Io(IoError::from(IoErrorKind::ConnectionReset)).is_retryable());
+        assert!(!Error::Io(IoError::from(IoErrorKind::InvalidInput)).is_retryable());
+
+        assert!(!Error::Model(ModelError::EmbedAmount).is_retryable());
+        assert!(!Error::ExceededLimit(String::new(), 512).is_retryable());
+        assert!(!Error::Other("other").is_retryable());
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn is_retryable_http() {
+        use reqwest::StatusCode;
+
+        use crate::http::error::{DiscordJsonError, Error as HttpError, ErrorResponse};
+
+        let response = |status_code: u16| {
+            Error::from(HttpError::UnsuccessfulRequest(ErrorResponse {
+                status_code: StatusCode::from_u16(status_code).unwrap(),
+                url: "https://discord.com/api/v9".parse().unwrap(),
+                error: DiscordJsonError {
+                    code: 0,
+                    message: String::new(),
+                    errors: vec![],
+                },
+            }))
+        };
+
+        assert!(response(503).is_retryable());
+        assert!(response(429).is_retryable());
+        assert!(!response(404).is_retryable());
+        assert!(!Error::from(HttpError::InvalidPort).is_retryable());
+    }
+}
@@ -75,6 +75,35 @@
 //! # }
 //! ```
 //!
+//! # Application commands
+//!
+//! With the `unstable_discord_api` feature, the standard framework can also run
+//! its commands when invoked as application (slash) commands, if enabled with
+//! `Configuration::application_commands`. The interaction's response is
+//! deferred, then edited into an acknowledgement naming the command, and the
+//! command is given a message built from that response.
+//!
+//! Replying to that message with `Message::reply` behaves the same as for any
+//! other message: a regular message is sent to the channel, replying to the
+//! acknowledgement. To respond to the interaction instead, use the
+//! `ApplicationCommandInvocation` of the message:
+//!
+//! ```rust,ignore
+//! #[command]
+//! async fn ping(ctx: &Context, msg: &Message) -> CommandResult {
+//!     match ApplicationCommandInvocation::of(ctx, msg) {
+//!         Some(invocation) => {
+//!             invocation.respond(ctx, |r| r.content("pong!")).await?;
+//!         },
+//!         None => {
+//!             msg.reply(ctx, "pong!").await?;
+//!         },
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
 //! [`ClientBuilder::framework`]: crate::client::ClientBuilder::framework
 
 #[cfg(feature = "standard_framework")]
@@ -86,6 +115,8 @@ use async_trait::async_trait;
 pub use self::standard::StandardFramework;
 use crate::client::Context;
 use crate::model::channel::Message;
+#[cfg(feature = "unstable_discord_api")]
+use crate::model::interactions::Interaction;
 
 /// A trait for defining your own framework for serenity to use.
 ///
@@ -98,6 +129,30 @@ use crate::model::channel::Message;
 #[async_trait]
 pub trait Framework: Send + Sync {
     async fn dispatch(&self, _: Context, _: Message);
+
+    /// Whether the framework responds to the [`Interaction`] itself.
+    ///
+    /// Interactions the framework handles are dispatched to it with
+    /// [`Self::interaction_dispatch`] instead of to the
+    /// [`EventHandler::interaction_create`] handler, so that only one of them
+    /// responds. Raw event handlers still receive them.
+    ///
+    /// By default, the framework handles no interactions.
+    ///
+    /// [`EventHandler::interaction_create`]: crate::client::EventHandler::interaction_create
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    fn handles_interaction(&self, _: &Interaction) -> bool {
+        false
+    }
+
+    /// Dispatches an [`Interaction`] received from Discord, which the
+    /// framework [handles].
+    ///
+    /// [handles]: Self::handles_interaction
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    async fn interaction_dispatch(&self, _: Context, _: Interaction) {}
 }
 
 #[async_trait]
@@ -109,6 +164,18 @@ where
     async fn dispatch(&self, ctx: Context, msg: Message) {
         (**self).dispatch(ctx, msg).await;
     }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[inline]
+    fn handles_interaction(&self, interaction: &Interaction) -> bool {
+        (**self).handles_interaction(interaction)
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[inline]
+    async fn interaction_dispatch(&self, ctx: Context, interaction: Interaction) {
+        (**self).interaction_dispatch(ctx, interaction).await;
+    }
 }
 
 #[async_trait]
@@ -120,4 +187,16 @@ where
     async fn dispatch(&self, ctx: Context, msg: Message) {
         (**self).dispatch(ctx, msg).await;
     }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[inline]
+    fn handles_interaction(&self, interaction: &Interaction) -> bool {
+        (**self).handles_interaction(interaction)
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[inline]
+    async fn interaction_dispatch(&self, ctx: Context, interaction: Interaction) {
+        (**self).interaction_dispatch(ctx, interaction).await;
+    }
 }
@@ -1,10 +1,17 @@
 use std::borrow::Cow;
 use std::error::Error as StdError;
 use std::marker::PhantomData;
+use std::time::Duration;
 use std::{fmt, str::FromStr};
 
 use uwl::Stream;
 
+use crate::http::CacheHttp;
+use crate::model::id::{GuildId, UserId};
+use crate::model::misc::UserParseError;
+use crate::model::user::User;
+use crate::utils::parse_tag;
+
 /// Defines how an operation on an [`Args`] method failed.
 #[derive(Debug)]
 #[non_exhaustive]
@@ -36,6 +43,86 @@ impl<E: fmt::Debug + fmt::Display> StdError for Error<E> {}
 
 type Result<T, E> = ::std::result::Result<T, Error<E>>;
 
+/// Defines how parsing a duration with [`Args::single_duration`] failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationError {
+    /// The argument was empty, such as a pair of quotes with nothing inside.
+    Empty,
+    /// A unit was not preceded by a quantity, such as the `m` in `1hm`.
+    MissingQuantity,
+    /// A quantity was not followed by a unit, such as the `30` in `1h30`.
+    MissingUnit,
+    /// The unit is not one of `w`, `d`, `h`, `m` or `s`. Units are
+    /// case-sensitive, so that `M` isn't mistaken for minutes when months
+    /// were meant.
+    InvalidUnit(char),
+    /// The duration is too large to be represented.
+    Overflow,
+}
+
+impl fmt::Display for DurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationError::Empty => f.write_str("empty duration"),
+            DurationError::MissingQuantity => f.write_str("unit without a quantity"),
+            DurationError::MissingUnit => f.write_str("quantity without a unit"),
+            DurationError::InvalidUnit(c) => write!(f, "invalid unit `{}`", c),
+            DurationError::Overflow => f.write_str("duration too large"),
+        }
+    }
+}
+
+impl StdError for DurationError {}
+
+/// Parses a compact duration, such as `1h30m`, `2d` or `90`.
+///
+/// Refer to [`Args::single_duration`] for the accepted grammar.
+fn parse_duration(s: &str) -> ::std::result::Result<Duration, DurationError> {
+    if s.is_empty() {
+        return Err(DurationError::Empty);
+    }
+
+    // A bare number is an amount of seconds.
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        return s.parse::<u64>().map(Duration::from_secs).map_err(|_| DurationError::Overflow);
+    }
+
+    let mut secs = 0u64;
+    let mut quantity: Option<u64> = None;
+
+    for c in s.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            let q = quantity.unwrap_or(0);
+            let q = q.checked_mul(10).and_then(|q| q.checked_add(u64::from(digit)));
+
+            quantity = Some(q.ok_or(DurationError::Overflow)?);
+
+            continue;
+        }
+
+        let unit = match c {
+            'w' => 60 * 60 * 24 * 7,
+            'd' => 60 * 60 * 24,
+            'h' => 60 * 60,
+            'm' => 60,
+            's' => 1,
+            other => return Err(DurationError::InvalidUnit(other)),
+        };
+
+        let q = quantity.take().ok_or(DurationError::MissingQuantity)?;
+
+        secs =
+            q.checked_mul(unit).and_then(|s| secs.checked_add(s)).ok_or(DurationError::Overflow)?;
+    }
+
+    if quantity.is_some() {
+        return Err(DurationError::MissingUnit);
+    }
+
+    Ok(Duration::from_secs(secs))
+}
+
 /// Dictates how [`Args`] should split arguments, if by one character, or a string.
 #[derive(Debug, Clone)]
 pub enum Delimiter {
@@ -45,7 +132,7 @@ pub enum Delimiter {
 
 impl Delimiter {
     #[inline]
-    fn to_str(&self) -> Cow<'_, str> {
+    pub(crate) fn to_str(&self) -> Cow<'_, str> {
         match self {
             Delimiter::Single(c) => Cow::Owned(c.to_string()),
             Delimiter::Multiple(s) => Cow::Borrowed(s),
@@ -289,6 +376,28 @@ impl Args {
     /// assert_eq!(args.rest(), "fox jumps over the lazy");
     /// ```
     pub fn new(message: &str, possible_delimiters: &[Delimiter]) -> Self {
+        Self::split(message, possible_delimiters, false)
+    }
+
+    /// Create a new instance of [`Args`] like [`Self::new`], but keeping the
+    /// empty arguments between consecutive delimiters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::{Args, Delimiter};
+    ///
+    /// let mut args = Args::with_empty_args("a,,c", &[Delimiter::Single(',')]);
+    ///
+    /// assert_eq!(args.single::<String>().unwrap(), "a");
+    /// assert_eq!(args.single::<String>().unwrap(), "");
+    /// assert_eq!(args.single::<String>().unwrap(), "c");
+    /// ```
+    pub fn with_empty_args(message: &str, possible_delimiters: &[Delimiter]) -> Self {
+        Self::split(message, possible_delimiters, true)
+    }
+
+    fn split(message: &str, possible_delimiters: &[Delimiter], keep_empty: bool) -> Self {
         let delims = possible_delimiters
             .iter()
             .filter(|d| match d {
@@ -312,8 +421,8 @@ impl Args {
             let mut stream = Stream::new(message);
 
             while let Some(token) = lex(&mut stream, &delims) {
-                // Ignore empty arguments.
-                if message[token.span.0..token.span.1].is_empty() {
+                // Ignore empty arguments, unless asked to keep them.
+                if !keep_empty && message[token.span.0..token.span.1].is_empty() {
                     continue;
                 }
 
@@ -331,6 +440,52 @@ impl Args {
         }
     }
 
+    /// Create a new instance of [`Args`] from arguments that have already been
+    /// split, joining them with the first of the delimiters.
+    ///
+    /// Arguments containing a delimiter or a quote are quoted, without being
+    /// lexed again, so that their quoted form always reads back as the original
+    /// argument, even if the argument contains quotes of its own.
+    #[cfg(feature = "unstable_discord_api")]
+    pub(crate) fn from_values(values: &[String], possible_delimiters: &[Delimiter]) -> Self {
+        let delims = possible_delimiters.iter().map(Delimiter::to_str).collect::<Vec<_>>();
+        let separator = delims.first().cloned().unwrap_or(Cow::Borrowed(" "));
+
+        let mut message = String::new();
+        let mut args = Vec::with_capacity(values.len());
+
+        for value in values {
+            if !message.is_empty() {
+                message.push_str(&separator);
+            }
+
+            let start = message.len();
+            let kind = if value.contains('"') || delims.iter().any(|d| value.contains(d.as_ref())) {
+                message.push('"');
+                message.push_str(value);
+                message.push('"');
+
+                TokenKind::QuotedArgument
+            } else {
+                message.push_str(value);
+
+                TokenKind::Argument
+            };
+
+            // Ignore empty arguments, as `Args::new` does.
+            if message.len() > start {
+                args.push(Token::new(kind, start, message.len()));
+            }
+        }
+
+        Args {
+            args,
+            message,
+            offset: 0,
+            state: State::None,
+        }
+    }
+
     #[inline]
     fn span(&self) -> (usize, usize) {
         self.args[self.offset].span
@@ -619,6 +774,144 @@ impl Args {
         Ok(p)
     }
 
+    /// Parse the current argument as a [`User`] and advance.
+    ///
+    /// The argument is resolved as, in order of precedence:
+    ///
+    /// 1. a raw user Id, e.g. `114941315417899012`;
+    /// 2. a user mention, e.g. `<@114941315417899012>` or `<@!114941315417899012>`;
+    /// 3. a `name#discriminator` tag, looked up in the member list of the
+    ///    guild with the given `guild_id`.
+    ///
+    /// Ids and mentions are resolved into a [`User`] via [`UserId::to_user`].
+    /// Tags are only resolved if `guild_id` is [`Some`]. They are looked up in
+    /// the cached guild first, if the `cache` feature is enabled, and are
+    /// otherwise searched for via [`GuildId::search_members`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::{Args, CommandResult, macros::command};
+    ///
+    /// #[command]
+    /// async fn whois(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    ///     let user = args.single_user(ctx, msg.guild_id).await?;
+    ///
+    ///     msg.reply(ctx, format!("That is {}", user.tag())).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Eos`] if there are no further remaining args.
+    ///
+    /// Returns [`UserParseError::InvalidUsername`] if the argument is neither an
+    /// Id, a mention, nor the tag of a member of the guild, or
+    /// [`UserParseError::Rest`] if the user could not be fetched.
+    pub async fn single_user(
+        &mut self,
+        cache_http: impl CacheHttp,
+        guild_id: Option<GuildId>,
+    ) -> Result<User, UserParseError> {
+        let user = self._user(cache_http, guild_id).await?;
+        self.advance();
+        Ok(user)
+    }
+
+    async fn _user(
+        &self,
+        cache_http: impl CacheHttp,
+        guild_id: Option<GuildId>,
+    ) -> Result<User, UserParseError> {
+        let arg = self.current().ok_or(Error::Eos)?;
+
+        if let Ok(user_id) = arg.parse::<UserId>() {
+            return user_id
+                .to_user(cache_http)
+                .await
+                .map_err(|e| Error::Parse(UserParseError::Rest(Box::new(e))));
+        }
+
+        let (guild_id, (name, discriminator)) = match (guild_id, parse_tag(arg)) {
+            (Some(guild_id), Some(tag)) => (guild_id, tag),
+            _ => return Err(Error::Parse(UserParseError::InvalidUsername)),
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                // Match the tag exactly, as `Guild::member_named` falls back to
+                // matching nicknames.
+                let user = cache
+                    .guild_field(guild_id, |guild| {
+                        guild
+                            .members
+                            .values()
+                            .map(|member| &member.user)
+                            .find(|user| user.name == name && user.discriminator == discriminator)
+                            .cloned()
+                    })
+                    .await
+                    .flatten();
+
+                if let Some(user) = user {
+                    return Ok(user);
+                }
+            }
+        }
+
+        let members = guild_id
+            .search_members(cache_http.http(), &name, Some(100))
+            .await
+            .map_err(|e| Error::Parse(UserParseError::Rest(Box::new(e))))?;
+
+        members
+            .into_iter()
+            .map(|member| member.user)
+            .find(|user| user.name == name && user.discriminator == discriminator)
+            .ok_or(Error::Parse(UserParseError::InvalidUsername))
+    }
+
+    /// Parse the current argument as a duration and advance.
+    ///
+    /// A duration is made up of integer quantities, each followed by one of
+    /// the units `w` (weeks), `d` (days), `h` (hours), `m` (minutes) or `s`
+    /// (seconds), such as `1h30m` or `2d`. The quantities of all units are
+    /// summed up. A bare integer, such as `90`, is an amount of seconds.
+    /// Units are case-sensitive, so `1M` is rejected rather than read as a
+    /// minute.
+    ///
+    /// Modifications of [`Self::trimmed`] and [`Self::quoted`] are also applied if they were called.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use serenity::framework::standard::{Args, Delimiter};
+    ///
+    /// let mut args = Args::new("1h30m 90", &[Delimiter::Single(' ')]);
+    ///
+    /// assert_eq!(args.single_duration().unwrap(), Duration::from_secs(5400));
+    /// assert_eq!(args.single_duration().unwrap(), Duration::from_secs(90));
+    /// assert!(args.is_empty());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Eos`] if there are no further remaining args, or
+    /// [`Error::Parse`] with a [`DurationError`] if the argument is not a
+    /// valid duration or is too large to be represented.
+    pub fn single_duration(&mut self) -> Result<Duration, DurationError> {
+        let p = parse_duration(self.current().ok_or(Error::Eos)?)?;
+        self.advance();
+        Ok(p)
+    }
+
     /// By starting from the current offset, iterate over
     /// any available arguments until there are none.
     ///
@@ -933,3 +1226,206 @@ impl<'a> Iterator for RawArguments<'a> {
         Some(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::{parse_duration, Args, Delimiter, DurationError, Error};
+
+    #[test]
+    fn duration_combined_units() {
+        assert_eq!(parse_duration("1h30m"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_duration("2d"), Ok(Duration::from_secs(172_800)));
+        assert_eq!(parse_duration("1w2d3h4m5s"), Ok(Duration::from_secs(788_645)));
+    }
+
+    #[test]
+    fn duration_bare_seconds() {
+        assert_eq!(parse_duration("90"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("90s"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("0"), Ok(Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn duration_invalid() {
+        assert_eq!(parse_duration(""), Err(DurationError::Empty));
+        assert_eq!(parse_duration("1M"), Err(DurationError::InvalidUnit('M')));
+        assert_eq!(parse_duration("1H30m"), Err(DurationError::InvalidUnit('H')));
+        assert_eq!(parse_duration("5x"), Err(DurationError::InvalidUnit('x')));
+        assert_eq!(parse_duration("-5s"), Err(DurationError::InvalidUnit('-')));
+        assert_eq!(parse_duration("h"), Err(DurationError::MissingQuantity));
+        assert_eq!(parse_duration("1hm"), Err(DurationError::MissingQuantity));
+        assert_eq!(parse_duration("1h30"), Err(DurationError::MissingUnit));
+        assert_eq!(parse_duration("99999999999999999999"), Err(DurationError::Overflow));
+        assert_eq!(parse_duration("99999999999999999w"), Err(DurationError::Overflow));
+
+        let mut args = Args::new(r#""" 5m"#, &[Delimiter::Single(' ')]);
+        let result = args.quoted().single_duration();
+        assert!(matches!(result, Err(Error::Parse(DurationError::Empty))));
+    }
+
+    #[test]
+    fn single_integer() {
+        let mut args = Args::new("42 rest of line", &[Delimiter::Single(' ')]);
+
+        assert!(matches!(args.single::<u32>(), Ok(42)));
+        assert_eq!(args.remains(), Some("rest of line"));
+    }
+
+    #[test]
+    fn single_quoted_with_spaces() {
+        let mut args = Args::new(r#""hello world" 2"#, &[Delimiter::Single(' ')]);
+
+        assert_eq!(args.single_quoted::<String>().ok().as_deref(), Some("hello world"));
+        assert!(matches!(args.single_quoted::<u32>(), Ok(2)));
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn single_non_numeric() {
+        let mut args = Args::new("abc 1", &[Delimiter::Single(' ')]);
+
+        assert!(matches!(args.single::<u32>(), Err(Error::Parse(_))));
+        assert_eq!(args.remains(), Some("abc 1"));
+
+        args.advance();
+        args.advance();
+
+        assert!(matches!(args.single::<u32>(), Err(Error::Eos)));
+    }
+
+    #[test]
+    fn comma_delimited() {
+        let args = Args::new("one,two,,three", &[Delimiter::Single(',')]);
+
+        assert_eq!(args.raw().collect::<Vec<_>>(), ["one", "two", "three"]);
+
+        let args = Args::with_empty_args("one,two,,three", &[Delimiter::Single(',')]);
+
+        assert_eq!(args.raw().collect::<Vec<_>>(), ["one", "two", "", "three"]);
+    }
+
+    #[test]
+    fn mixed_delimiters() {
+        let delimiters = [Delimiter::Single(' '), Delimiter::Single(',')];
+        let args = Args::new("one, two three,four", &delimiters);
+
+        assert_eq!(args.raw().collect::<Vec<_>>(), ["one", "two", "three", "four"]);
+
+        let args = Args::with_empty_args("one, two three,four", &delimiters);
+
+        assert_eq!(args.raw().collect::<Vec<_>>(), ["one", "", "two", "three", "four"]);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn single_user() {
+        use std::sync::Arc;
+
+        use crate::cache::Cache;
+        use crate::http::mock;
+        use crate::model::fixtures;
+        use crate::model::id::{GuildId, UserId};
+        use crate::model::misc::UserParseError;
+
+        let user = |id| serde_json::to_string(&fixtures::member(id, &[]).user).unwrap();
+        let (proxy, server) = mock::serve(vec![
+            (200, user(9)),
+            (200, user(9)),
+            (200, format!("[{}]", serde_json::to_string(&fixtures::member(9, &[])).unwrap())),
+            (200, "[]".to_string()),
+            (404, r#"{"code": 10013, "message": "Unknown User"}"#.to_string()),
+        ]);
+        let http = mock::http(proxy).await;
+
+        let cache = Arc::new(Cache::default());
+        let user = fixtures::member(7, &[]).user;
+        cache.users.write().await.insert(user.id, user);
+        cache
+            .guilds
+            .write()
+            .await
+            .insert(GuildId(1), fixtures::guild(vec![], vec![fixtures::member(8, &[])]));
+
+        let cache_http = (&cache, &http);
+        let guild_id = Some(GuildId(1));
+        let mut args = Args::new(
+            "7,<@7>,<@!7>,user 8#0001,user 8#0001,9,<@!9>,user 9#0001,user 10#0001,10,user 9",
+            &[Delimiter::Single(',')],
+        );
+
+        // Cached users are resolved without a request, whether by Id, mention
+        // or tag.
+        for _ in 0..3 {
+            let user = args.single_user(cache_http, guild_id).await.unwrap();
+            assert_eq!(user.id, UserId(7));
+        }
+
+        let user = args.single_user(cache_http, guild_id).await.unwrap();
+        assert_eq!(user.id, UserId(8));
+
+        // Tags can't be resolved outside of a guild.
+        let result = args.single_user(cache_http, None).await;
+        assert!(matches!(result, Err(Error::Parse(UserParseError::InvalidUsername))));
+        args.advance();
+
+        // Uncached Ids and mentions are fetched, and only the remaining tags
+        // are searched for among the guild's members.
+        for _ in 0..3 {
+            let user = args.single_user(cache_http, guild_id).await.unwrap();
+            assert_eq!(user.id, UserId(9));
+        }
+
+        let result = args.single_user(cache_http, guild_id).await;
+        assert!(matches!(result, Err(Error::Parse(UserParseError::InvalidUsername))));
+        args.advance();
+
+        let result = args.single_user(cache_http, guild_id).await;
+        assert!(matches!(result, Err(Error::Parse(UserParseError::Rest(_)))));
+        args.advance();
+
+        let result = args.single_user(cache_http, guild_id).await;
+        assert!(matches!(result, Err(Error::Parse(UserParseError::InvalidUsername))));
+        args.advance();
+
+        assert!(matches!(args.single_user(cache_http, guild_id).await, Err(Error::Eos)));
+
+        assert_eq!(server.join().unwrap(), [
+            "GET /api/v9/users/9 HTTP/1.1",
+            "GET /api/v9/users/9 HTTP/1.1",
+            "GET /api/v9/guilds/1/members/search?&query=user%209&limit=100 HTTP/1.1",
+            "GET /api/v9/guilds/1/members/search?&query=user%2010&limit=100 HTTP/1.1",
+            "GET /api/v9/users/10 HTTP/1.1",
+        ]);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn single_user_ignores_nicknames() {
+        use std::sync::Arc;
+
+        use crate::cache::Cache;
+        use crate::http::mock;
+        use crate::model::fixtures;
+        use crate::model::id::{GuildId, UserId};
+
+        let member = serde_json::to_string(&fixtures::member(9, &[])).unwrap();
+        let (proxy, server) = mock::serve(vec![(200, format!("[{}]", member))]);
+        let http = mock::http(proxy).await;
+
+        // The cached member's nickname is the name of the tag's user.
+        let mut nicknamed = fixtures::member(8, &[]);
+        nicknamed.nick = Some("user 9".to_string());
+        let cache = Arc::new(Cache::default());
+        cache.guilds.write().await.insert(GuildId(1), fixtures::guild(vec![], vec![nicknamed]));
+
+        let mut args = Args::new("user 9#0001", &[Delimiter::Single(',')]);
+        let user = args.single_user((&cache, &http), Some(GuildId(1))).await.unwrap();
+
+        assert_eq!(user.id, UserId(9));
+        assert_eq!(server.join().unwrap(), [
+            "GET /api/v9/guilds/1/members/search?&query=user%209&limit=100 HTTP/1.1",
+        ]);
+    }
+}
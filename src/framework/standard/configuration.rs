@@ -12,6 +12,9 @@ use crate::model::{
 type DynamicPrefixHook =
     for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<String>>;
 
+type PrefixMatcherHook =
+    for<'fut> fn(&'fut Context, &'fut Message) -> BoxFuture<'fut, Option<usize>>;
+
 /// A configuration struct for deciding whether the framework
 /// should allow optional whitespace between prefixes, group prefixes and command names.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -103,6 +106,9 @@ impl From<(bool, bool, bool)> for WithWhiteSpace {
 pub struct Configuration {
     #[doc(hidden)]
     pub allow_dm: bool,
+    #[cfg(feature = "unstable_discord_api")]
+    #[doc(hidden)]
+    pub application_commands: bool,
     #[doc(hidden)]
     pub with_whitespace: WithWhiteSpace,
     #[doc(hidden)]
@@ -118,6 +124,8 @@ pub struct Configuration {
     #[doc(hidden)]
     pub dynamic_prefixes: Vec<DynamicPrefixHook>,
     #[doc(hidden)]
+    pub prefix_matchers: Vec<PrefixMatcherHook>,
+    #[doc(hidden)]
     pub ignore_bots: bool,
     #[doc(hidden)]
     pub ignore_webhooks: bool,
@@ -132,6 +140,8 @@ pub struct Configuration {
     #[doc(hidden)]
     pub delimiters: Vec<Delimiter>,
     #[doc(hidden)]
+    pub empty_args: bool,
+    #[doc(hidden)]
     pub case_insensitive: bool,
 }
 
@@ -145,6 +155,48 @@ impl Configuration {
         self
     }
 
+    /// Whether application (slash) commands should be dispatched to the
+    /// command of the same name.
+    ///
+    /// The interaction's response is deferred before running the command, then
+    /// edited into an acknowledgement naming the command. The command is given
+    /// a [`Message`] built from the acknowledgement, authored by the invoking
+    /// user, with the values of the interaction's options as its [`Args`].
+    /// Options of the sub-command type select the command's sub-command of the
+    /// same name. The message's `interaction` field is set, letting commands
+    /// tell the two kinds of invocation apart.
+    ///
+    /// The message is an ordinary [`Message`]: [`Message::reply`] sends a
+    /// regular message to the channel, replying to the acknowledgement, and
+    /// reacting to or deleting the message acts on the acknowledgement. To
+    /// respond to the interaction itself with a follow-up message, retrieve
+    /// the [`ApplicationCommandInvocation`] of the message. Respond from the
+    /// [`StandardFramework::after`] and [`StandardFramework::on_dispatch_error`]
+    /// hooks to tell the user about commands that failed or did not run.
+    ///
+    /// Checks, buckets, required permissions and roles apply the same way they
+    /// do to prefixed commands. Group prefixes are ignored, as application
+    /// commands have none.
+    ///
+    /// Application commands naming one of the framework's commands are not
+    /// given to [`EventHandler::interaction_create`], while those that don't
+    /// are left alone, to be responded to there.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// [`Args`]: super::Args
+    /// [`ApplicationCommandInvocation`]: super::ApplicationCommandInvocation
+    /// [`StandardFramework::after`]: super::StandardFramework::after
+    /// [`StandardFramework::on_dispatch_error`]: super::StandardFramework::on_dispatch_error
+    /// [`EventHandler::interaction_create`]: crate::client::EventHandler::interaction_create
+    #[cfg(feature = "unstable_discord_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+    pub fn application_commands(&mut self, application_commands: bool) -> &mut Self {
+        self.application_commands = application_commands;
+
+        self
+    }
+
     /// Whether to allow whitespace being optional between a prefix/group-prefix/command and
     /// a command.
     ///
@@ -356,6 +408,49 @@ impl Configuration {
         self
     }
 
+    /// Matches the prefix of a message with a function, in addition to the
+    /// prefixes configured with [`Self::prefix`], [`Self::prefixes`] and
+    /// [`Self::dynamic_prefix`].
+    ///
+    /// The function returns the length in bytes of the prefix at the start of
+    /// the message's content, or [`None`] if the message does not start with a
+    /// prefix. This is useful for prefixes that can't be listed up front, such
+    /// as one matched by a regular expression.
+    ///
+    /// Matchers are tried in the order they were added, before any other
+    /// prefix. A mention of the bot, if [`Self::on_mention`] is set, is still
+    /// checked first.
+    ///
+    /// This method can be called many times to add more prefix matchers.
+    ///
+    /// **Note**: Defaults to no prefix matcher.
+    ///
+    /// # Examples
+    ///
+    /// Use one to three exclamation marks as the prefix:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// let framework = StandardFramework::new().configure(|c| {
+    ///     c.prefix_matcher(|_, msg| {
+    ///         Box::pin(async move {
+    ///             match msg.content.bytes().take_while(|b| *b == b'!').count() {
+    ///                 0 => None,
+    ///                 n => Some(n.min(3)),
+    ///             }
+    ///         })
+    ///     })
+    /// });
+    /// ```
+    #[inline]
+    pub fn prefix_matcher(&mut self, prefix_matcher: PrefixMatcherHook) -> &mut Self {
+        self.prefix_matchers.push(prefix_matcher);
+
+        self
+    }
+
     /// Whether the bot should respond to other bots.
     ///
     /// For example, if this is set to false, then the bot will respond to any
@@ -554,6 +649,28 @@ impl Configuration {
         self
     }
 
+    /// Whether consecutive delimiters should produce empty arguments, rather
+    /// than being treated as one.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// Have `~cmd a,,c` be given the arguments `a`, an empty one, and `c`:
+    ///
+    /// ```rust,no_run
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// let framework = StandardFramework::new().configure(|c| c
+    ///     .delimiter(',')
+    ///     .empty_args(true));
+    /// ```
+    pub fn empty_args(&mut self, b: bool) -> &mut Self {
+        self.empty_args = b;
+
+        self
+    }
+
     /// Whether the framework shouldn't care about the user's input if it's:
     /// `~command`, `~Command`, or `~COMMAND`; `mayacommand`, `MayACommand`, `MAYACOMMAND`, et cetera.
     ///
@@ -576,6 +693,7 @@ impl Default for Configuration {
     /// Builds a default framework configuration, setting the following:
     ///
     /// - **allow_dm** to `true`
+    /// - **application_commands** to `false`
     /// - **with_whitespace** to `(false, true, true)`
     /// - **by_space** to `true`
     /// - **blocked_guilds** to an empty HashSet
@@ -585,6 +703,8 @@ impl Default for Configuration {
     /// - **delimiters** to `vec![' ']`
     /// - **disabled_commands** to an empty HashSet
     /// - **dynamic_prefixes** to an empty vector
+    /// - **empty_args** to `false`
+    /// - **prefix_matchers** to an empty vector
     /// - **ignore_bots** to `true`
     /// - **ignore_webhooks** to `true`
     /// - **no_dm_prefix** to `false`
@@ -594,6 +714,8 @@ impl Default for Configuration {
     fn default() -> Configuration {
         Configuration {
             allow_dm: true,
+            #[cfg(feature = "unstable_discord_api")]
+            application_commands: false,
             with_whitespace: WithWhiteSpace::default(),
             by_space: true,
             blocked_guilds: HashSet::default(),
@@ -603,6 +725,8 @@ impl Default for Configuration {
             delimiters: vec![Delimiter::Single(' ')],
             disabled_commands: HashSet::default(),
             dynamic_prefixes: Vec::new(),
+            empty_args: false,
+            prefix_matchers: Vec::new(),
             ignore_bots: true,
             ignore_webhooks: true,
             no_dm_prefix: false,
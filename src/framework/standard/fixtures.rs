@@ -0,0 +1,87 @@
+//! A context, messages and commands shared by the unit tests of the
+//! framework.
+
+use std::sync::Arc;
+
+use futures::channel::mpsc::unbounded;
+use serde_json::json;
+use tokio::sync::RwLock;
+use typemap_rev::TypeMap;
+
+use super::{Command, CommandFn, CommandGroup, CommandOptions, GroupOptions};
+use crate::client::Context;
+use crate::http::Http;
+use crate::model::channel::Message;
+
+/// Builds a context with an empty cache and a client without a token.
+pub fn context() -> Context {
+    context_with(Http::new_with_token(""))
+}
+
+/// Builds a context with an empty cache, making requests with `http`.
+#[cfg(feature = "cache")]
+pub fn context_with(http: Http) -> Context {
+    let data = Arc::new(RwLock::new(TypeMap::new()));
+
+    Context::new(data, unbounded().0, 0, Arc::new(http), Arc::default())
+}
+
+/// Builds a context making requests with `http`.
+#[cfg(not(feature = "cache"))]
+pub fn context_with(http: Http) -> Context {
+    let data = Arc::new(RwLock::new(TypeMap::new()));
+
+    Context::new(data, unbounded().0, 0, Arc::new(http))
+}
+
+/// Builds a message with an Id of `3`, sent by the user with an Id of `2` in
+/// the private channel with an Id of `2`.
+pub fn message(content: &str) -> Message {
+    serde_json::from_value(json!({
+        "id": "3",
+        "attachments": [],
+        "author": {
+            "id": "2",
+            "avatar": null,
+            "discriminator": "0001",
+            "username": "user 2",
+        },
+        "channel_id": "2",
+        "content": content,
+        "edited_timestamp": null,
+        "embeds": [],
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": "2021-01-01T00:00:00+00:00",
+        "tts": false,
+        "type": 0,
+    }))
+    .expect("valid message")
+}
+
+/// Builds a command called by the `names`, running `fun`.
+///
+/// Commands and groups are leaked to give them the `'static` lifetime the
+/// framework expects of them.
+pub fn command(names: &'static [&'static str], fun: CommandFn) -> &'static Command {
+    Box::leak(Box::new(Command {
+        fun,
+        options: Box::leak(Box::new(CommandOptions {
+            names,
+            ..Default::default()
+        })),
+    }))
+}
+
+/// Builds a group named `general`, containing the `commands`.
+pub fn group(commands: Vec<&'static Command>) -> &'static CommandGroup {
+    Box::leak(Box::new(CommandGroup {
+        name: "general",
+        options: Box::leak(Box::new(GroupOptions {
+            commands: Box::leak(commands.into_boxed_slice()),
+            ..Default::default()
+        })),
+    }))
+}
@@ -281,17 +281,9 @@ pub async fn has_all_requirements(
     if let Some(guild_id) = msg.guild_id {
         if let Some(member) = cache.member(guild_id, &msg.author.id).await {
             if let Ok(permissions) = member.permissions(&cache_http).await {
-                return if cmd.allowed_roles.is_empty() {
-                    permissions.administrator() || has_correct_permissions(&cache, &cmd, msg).await
-                } else if let Some(roles) = cache.guild_roles(guild_id).await {
-                    permissions.administrator()
-                        || (has_correct_roles(&cmd, &roles, &member)
-                            && has_correct_permissions(&cache, &cmd, msg).await)
-                } else {
-                    warn!("Failed to find the guild and its roles.");
-
-                    false
-                };
+                return permissions.administrator()
+                    || (has_correct_roles(&cache, &cmd, guild_id, &member).await
+                        && has_correct_permissions(&cache, &cmd, msg).await);
             }
         }
     }
@@ -339,17 +331,15 @@ async fn check_common_behaviour(
         return help_options.lacking_permissions;
     }
 
-    msg.guild_field(&cache_http, |guild| {
-        if let Some(member) = guild.members.get(&msg.author.id) {
-            if !has_correct_roles(options, &guild.roles, member) {
+    if let Some(guild_id) = msg.guild_id {
+        if let Some(member) = cache_http.as_ref().member(guild_id, msg.author.id).await {
+            if !has_correct_roles(&cache_http, options, guild_id, &member).await {
                 return help_options.lacking_role;
             }
         }
+    }
 
-        HelpBehaviour::Nothing
-    })
-    .await
-    .unwrap_or(HelpBehaviour::Nothing)
+    HelpBehaviour::Nothing
 }
 
 #[cfg(all(feature = "cache", feature = "http"))]
@@ -1615,3 +1605,105 @@ mod matrix_tests {
         matrix[(0, 0)];
     }
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "cache", feature = "http"))]
+mod behaviour_tests {
+    use std::collections::HashSet;
+
+    use super::check_command_behaviour;
+    use crate::framework::standard::{fixtures, CommandOptions, HelpBehaviour, HelpOptions};
+    use crate::model::fixtures as model_fixtures;
+    use crate::model::id::{ChannelId, GuildId, RoleId, UserId};
+    use crate::model::Permissions;
+    use crate::utils::Colour;
+
+    fn help_options() -> HelpOptions {
+        HelpOptions {
+            names: &["help"],
+            suggestion_text: "",
+            no_help_available_text: "",
+            usage_label: "",
+            usage_sample_label: "",
+            ungrouped_label: "",
+            description_label: "",
+            grouped_label: "",
+            aliases_label: "",
+            guild_only_text: "",
+            checks_label: "",
+            sub_commands_label: "",
+            dm_only_text: "",
+            dm_and_guild_text: "",
+            available_text: "",
+            command_not_found_text: "",
+            individual_command_tip: "",
+            strikethrough_commands_tip_in_dm: None,
+            strikethrough_commands_tip_in_guild: None,
+            group_prefix: "",
+            lacking_role: HelpBehaviour::Hide,
+            lacking_permissions: HelpBehaviour::Hide,
+            lacking_ownership: HelpBehaviour::Hide,
+            lacking_conditions: HelpBehaviour::Hide,
+            wrong_channel: HelpBehaviour::Hide,
+            embed_error_colour: Colour::default(),
+            embed_success_colour: Colour::default(),
+            max_levenshtein_distance: 0,
+            indention_prefix: "-",
+        }
+    }
+
+    #[tokio::test]
+    async fn privileged_command_hidden() {
+        let ctx = fixtures::context();
+        let mut admin = model_fixtures::role(5, 1);
+        admin.permissions = Permissions::ADMINISTRATOR;
+        let mut guild = model_fixtures::guild(
+            vec![model_fixtures::role(1, 0), admin],
+            vec![model_fixtures::member(2, &[])],
+        );
+        guild.channels.insert(ChannelId(2), model_fixtures::channel(2));
+        ctx.cache.guilds.write().await.insert(guild.id, guild);
+
+        let mut msg = fixtures::message("~help");
+        msg.guild_id = Some(GuildId(1));
+        let owners = HashSet::new();
+        let help_options = help_options();
+
+        let privileged = CommandOptions {
+            help_available: true,
+            required_permissions: Permissions::ADMINISTRATOR,
+            ..Default::default()
+        };
+        let behaviour =
+            check_command_behaviour(&ctx, &msg, &privileged, &[], &owners, &help_options).await;
+
+        assert_eq!(behaviour, HelpBehaviour::Hide);
+
+        let unprivileged = CommandOptions {
+            help_available: true,
+            ..Default::default()
+        };
+        let behaviour =
+            check_command_behaviour(&ctx, &msg, &unprivileged, &[], &owners, &help_options).await;
+
+        assert_eq!(behaviour, HelpBehaviour::Nothing);
+
+        let unlisted = CommandOptions::default();
+        let behaviour =
+            check_command_behaviour(&ctx, &msg, &unlisted, &[], &owners, &help_options).await;
+
+        assert_eq!(behaviour, HelpBehaviour::Hide);
+
+        // Once the member is an administrator, the command is listed.
+        if let Some(guild) = ctx.cache.guilds.write().await.get_mut(&GuildId(1)) {
+            if let Some(member) = guild.members.get_mut(&UserId(2)) {
+                member.roles.push(RoleId(5));
+            }
+        }
+
+        let behaviour =
+            check_command_behaviour(&ctx, &msg, &privileged, &[], &owners, &help_options).await;
+
+        assert_eq!(behaviour, HelpBehaviour::Nothing);
+    }
+}
@@ -5,18 +5,23 @@ pub mod macros {
 
 mod args;
 mod configuration;
+#[cfg(test)]
+mod fixtures;
 mod parse;
 mod structures;
 
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
 
-pub use args::{Args, Delimiter, Error as ArgError, Iter, RawArguments};
+pub use args::{Args, Delimiter, DurationError, Error as ArgError, Iter, RawArguments};
 use async_trait::async_trait;
 pub use configuration::{Configuration, WithWhiteSpace};
 use futures::future::BoxFuture;
 use parse::map::{CommandMap, GroupMap, Map};
 use parse::{Invoke, ParseError};
+#[cfg(feature = "unstable_discord_api")]
+use serde_json::Value;
 pub use structures::buckets::BucketBuilder;
 use structures::buckets::{Bucket, RateLimitAction};
 pub use structures::*;
@@ -30,16 +35,35 @@ use uwl::Stream;
 
 use self::buckets::{RateLimitInfo, RevertBucket};
 use super::Framework;
+#[cfg(feature = "unstable_discord_api")]
+use crate::builder::CreateInteractionResponseFollowup;
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
 use crate::client::Context;
+#[cfg(feature = "unstable_discord_api")]
+use crate::http::Http;
 #[cfg(feature = "cache")]
 use crate::model::channel::Channel;
 #[cfg(feature = "cache")]
 use crate::model::guild::Member;
-use crate::model::{channel::Message, permissions::Permissions};
-#[cfg(all(feature = "cache", feature = "http", feature = "model"))]
-use crate::model::{guild::Role, id::RoleId};
+#[cfg(feature = "cache")]
+use crate::model::id::GuildId;
+use crate::model::{channel::Message, id::RoleId, permissions::Permissions};
+#[cfg(feature = "unstable_discord_api")]
+use crate::model::{
+    guild::PartialMember,
+    id::MessageId,
+    interactions::{
+        application_command::{
+            ApplicationCommandInteraction,
+            ApplicationCommandInteractionDataOption,
+        },
+        Interaction,
+        InteractionResponseType,
+        InteractionType,
+        MessageInteraction,
+    },
+};
 
 /// An enum representing all possible fail conditions under which a command won't
 /// be executed.
@@ -76,9 +100,90 @@ pub enum DispatchError {
     TooManyArguments { max: u16, given: usize },
 }
 
+/// The decision of a [`StandardFramework::before_action`] hook on whether to
+/// run a command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum BeforeAction {
+    /// Run the command.
+    Run,
+    /// Skip the command for the given reason.
+    ///
+    /// The reason is passed on to the [`StandardFramework::after`] hook as a
+    /// [`CommandSkipped`] error.
+    Skip(String),
+}
+
+/// The error given to the [`StandardFramework::after`] hook in place of the
+/// command's result when a [`StandardFramework::before_action`] hook skipped
+/// the command, containing the reason for skipping it.
+#[derive(Clone, Debug)]
+pub struct CommandSkipped(pub String);
+
+impl std::fmt::Display for CommandSkipped {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Command skipped: {}", self.0)
+    }
+}
+
+impl std::error::Error for CommandSkipped {}
+
+/// An application command being run by the framework in place of a message.
+///
+/// While the command, its hooks and checks run, the invocation is carried by
+/// the [`Context`] they are given, and can be retrieved with [`Self::of`] to
+/// respond to the interaction.
+#[cfg(feature = "unstable_discord_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
+#[derive(Debug)]
+pub struct ApplicationCommandInvocation {
+    interaction: ApplicationCommandInteraction,
+    message_id: MessageId,
+}
+
+#[cfg(feature = "unstable_discord_api")]
+impl ApplicationCommandInvocation {
+    fn new(interaction: ApplicationCommandInteraction, message_id: MessageId) -> Self {
+        Self {
+            interaction,
+            message_id,
+        }
+    }
+
+    /// Retrieves the invocation the `msg` was built from, if it was built from
+    /// an application command.
+    pub fn of(ctx: &Context, msg: &Message) -> Option<Arc<Self>> {
+        ctx.application_command.as_ref().filter(|i| i.message_id == msg.id).cloned()
+    }
+
+    /// The interaction that invoked the command.
+    pub fn interaction(&self) -> &ApplicationCommandInteraction {
+        &self.interaction
+    }
+
+    /// Responds to the interaction with a follow-up message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as
+    /// [`ApplicationCommandInteraction::create_followup_message`].
+    pub async fn respond<'a, F>(&self, http: impl AsRef<Http>, f: F) -> crate::Result<Message>
+    where
+        for<'b> F: FnOnce(
+            &'b mut CreateInteractionResponseFollowup<'a>,
+        ) -> &'b mut CreateInteractionResponseFollowup<'a>,
+    {
+        self.interaction.create_followup_message(http, f).await
+    }
+}
+
 type DispatchHook =
     for<'fut> fn(&'fut Context, &'fut Message, DispatchError) -> BoxFuture<'fut, ()>;
 type BeforeHook = for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, bool>;
+type BeforeActionHook =
+    for<'fut> fn(&'fut Context, &'fut Message, &'fut str) -> BoxFuture<'fut, BeforeAction>;
 type AfterHook = for<'fut> fn(
     &'fut Context,
     &'fut Message,
@@ -100,6 +205,7 @@ pub struct StandardFramework {
     groups: Vec<(&'static CommandGroup, Map)>,
     buckets: Mutex<HashMap<String, Bucket>>,
     before: Option<BeforeHook>,
+    before_action: Option<BeforeActionHook>,
     after: Option<AfterHook>,
     dispatch: Option<DispatchHook>,
     unrecognised_command: Option<UnrecognisedHook>,
@@ -215,6 +321,40 @@ impl StandardFramework {
             || (self.config.ignore_webhooks && msg.webhook_id.is_some())
     }
 
+    /// Splits the `message` into arguments at the `delimiters`, keeping empty
+    /// arguments if configured to.
+    fn args(&self, message: &str, delimiters: &[Delimiter]) -> Args {
+        if self.config.empty_args {
+            Args::with_empty_args(message, delimiters)
+        } else {
+            Args::new(message, delimiters)
+        }
+    }
+
+    /// The delimiters to split the arguments of the `command` by.
+    fn delimiters(&self, command: &Command) -> Cow<'_, [Delimiter]> {
+        // If user has configured the command's own delimiters, use those instead.
+        if command.options.delimiters.is_empty() {
+            return Cow::Borrowed(&self.config.delimiters);
+        }
+
+        // FIXME: Get rid of this allocation.
+        let mut v = Vec::with_capacity(command.options.delimiters.len());
+
+        for delim in command.options.delimiters {
+            if delim.len() == 1 {
+                // Should always be Some() in this case
+                #[allow(clippy::unwrap_used)]
+                v.push(Delimiter::Single(delim.chars().next().unwrap()));
+            } else {
+                // This too.
+                v.push(Delimiter::Multiple(delim.to_string()));
+            }
+        }
+
+        Cow::Owned(v)
+    }
+
     async fn should_fail<'a>(
         &'a self,
         ctx: &'a Context,
@@ -223,22 +363,8 @@ impl StandardFramework {
         command: &'static CommandOptions,
         group: &'static GroupOptions,
     ) -> Option<DispatchError> {
-        if let Some(min) = command.min_args {
-            if args.len() < min as usize {
-                return Some(DispatchError::NotEnoughArguments {
-                    min,
-                    given: args.len(),
-                });
-            }
-        }
-
-        if let Some(max) = command.max_args {
-            if args.len() > max as usize {
-                return Some(DispatchError::TooManyArguments {
-                    max,
-                    given: args.len(),
-                });
-            }
+        if let Some(error) = check_argument_count(command, args) {
+            return Some(error);
         }
 
         if (group.owner_privilege && command.owner_privilege)
@@ -503,9 +629,63 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called prior to every command's execution,
+    /// deciding whether to run the command.
+    ///
+    /// Unlike with [`Self::before`], a skipped command still calls the
+    /// [`Self::after`] hook, which receives a [`CommandSkipped`] error with
+    /// the reason for skipping it. If both hooks are set, this is called after
+    /// [`Self::before`] allowed the command to run.
+    ///
+    /// # Examples
+    ///
+    /// Skipping commands outside of guilds, and logging why:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// use serenity::framework::standard::macros::hook;
+    /// use serenity::framework::standard::{BeforeAction, CommandError, CommandSkipped};
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// #[hook]
+    /// async fn before_hook(_: &Context, msg: &Message, _: &str) -> BeforeAction {
+    ///     if msg.guild_id.is_none() {
+    ///         return BeforeAction::Skip("not in a guild".to_string());
+    ///     }
+    ///
+    ///     BeforeAction::Run
+    /// }
+    ///
+    /// #[hook]
+    /// async fn after_hook(_: &Context, _: &Message, cmd_name: &str, error: Result<(), CommandError>) {
+    ///     if let Err(why) = error {
+    ///         match why.downcast_ref::<CommandSkipped>() {
+    ///             Some(CommandSkipped(reason)) => println!("Skipped {}: {}", cmd_name, reason),
+    ///             None => println!("Error in {}: {:?}", cmd_name, why),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let framework = StandardFramework::new()
+    ///     .before_action(before_hook)
+    ///     .after(after_hook);
+    /// ```
+    pub fn before_action(mut self, f: BeforeActionHook) -> Self {
+        self.before_action = Some(f);
+
+        self
+    }
+
     /// Specify the function to be called after every command's execution.
     /// Fourth argument exists if command returned an error which you can handle.
     ///
+    /// **Note**: This is also called for commands skipped by the
+    /// [`Self::before_action`] hook. Their fourth argument is an `Err`
+    /// containing a [`CommandSkipped`] with the reason for skipping, rather
+    /// than an error returned by the command. Downcast the error to tell the
+    /// two apart, as shown below.
+    ///
     /// # Examples
     ///
     /// Using [`Self::after`] to log command usage:
@@ -514,14 +694,16 @@ impl StandardFramework {
     /// # use serenity::prelude::*;
     /// # use serenity::model::prelude::*;
     /// use serenity::framework::standard::macros::hook;
-    /// use serenity::framework::standard::CommandError;
+    /// use serenity::framework::standard::{CommandError, CommandSkipped};
     /// use serenity::framework::StandardFramework;
     ///
     /// #[hook]
     /// async fn after_hook(_: &Context, _: &Message, cmd_name: &str, error: Result<(), CommandError>) {
-    ///     //  Print out an error if it happened
+    ///     //  Print out an error if it happened, ignoring skipped commands
     ///     if let Err(why) = error {
-    ///         println!("Error in {}: {:?}", cmd_name, why);
+    ///         if why.downcast_ref::<CommandSkipped>().is_none() {
+    ///             println!("Error in {}: {:?}", cmd_name, why);
+    ///         }
     ///     }
     /// }
     ///
@@ -588,6 +770,144 @@ impl StandardFramework {
         self
     }
 
+    #[cfg(feature = "unstable_discord_api")]
+    async fn dispatch_application_command(
+        &self,
+        mut ctx: Context,
+        interaction: ApplicationCommandInteraction,
+    ) {
+        let (path, command) =
+            match parse::find_application_command(&interaction.data, &self.groups, &self.config) {
+                Some(found) => found,
+                None => return,
+            };
+
+        // Defer the response, as commands may take longer than Discord is
+        // willing to wait for one.
+        let ack = interaction
+            .create_interaction_response(&ctx.http, |r| {
+                r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+            })
+            .await;
+
+        if let Err(why) = ack {
+            tracing::warn!("Error acknowledging application command {}: {:?}", interaction.id, why);
+
+            return;
+        }
+
+        // Turn the deferred response into an acknowledgement of the command,
+        // which stands in for the message invoking it.
+        let response = interaction
+            .edit_original_interaction_response(&ctx.http, |r| {
+                r.content(format!("/{}", interaction.data.name))
+            })
+            .await;
+
+        let response = match response {
+            Ok(response) => response,
+            Err(why) => {
+                tracing::warn!("Error editing response to {}: {:?}", interaction.id, why);
+
+                return;
+            },
+        };
+
+        let msg = application_command_message(&interaction, response);
+
+        // Let the command and its hooks respond to the interaction for the
+        // duration of the dispatch.
+        let invocation = Arc::new(ApplicationCommandInvocation::new(interaction, msg.id));
+        ctx.application_command = Some(Arc::clone(&invocation));
+
+        let data = &invocation.interaction().data;
+        let invoke =
+            parse::application_command(&ctx, &msg, data, &path, command, &self.config).await;
+
+        let (group, command, options) = match invoke {
+            Ok(invoke) => invoke,
+            Err(error) => {
+                if let Some(dispatch) = &self.dispatch {
+                    dispatch(&mut ctx, &msg, error).await;
+                }
+
+                return;
+            },
+        };
+
+        let mut args = application_command_args(options, &self.delimiters(command));
+
+        if let Some(error) =
+            self.should_fail(&ctx, &msg, &mut args, command.options, group.options).await
+        {
+            if let Some(dispatch) = &self.dispatch {
+                dispatch(&mut ctx, &msg, error).await;
+            }
+
+            return;
+        }
+
+        self.run_command(&mut ctx, &msg, command, args).await;
+    }
+
+    /// Runs the `command` along with the [`Self::before`] and [`Self::after`]
+    /// hooks, giving back a bucket ticket if the command asks for it.
+    async fn run_command(
+        &self,
+        ctx: &mut Context,
+        msg: &Message,
+        command: &'static Command,
+        args: Args,
+    ) {
+        let name = command.options.names[0];
+
+        if !self.run_before_hooks(ctx, msg, name).await {
+            return;
+        }
+
+        let res = (command.fun)(ctx, msg, args).await;
+
+        // Check if the command wants to revert the bucket by giving back a ticket.
+        if matches!(res, Err(ref e) if e.is::<RevertBucket>()) {
+            let mut buckets = self.buckets.lock().await;
+
+            if let Some(ref mut bucket) =
+                command.options.bucket.as_ref().and_then(|b| buckets.get_mut(*b))
+            {
+                bucket.give(ctx, msg).await;
+            }
+        }
+
+        if let Some(after) = &self.after {
+            after(ctx, msg, name, res).await;
+        }
+    }
+
+    /// Runs the [`Self::before`] and [`Self::before_action`] hooks, returning
+    /// whether the command `name` should be run.
+    ///
+    /// If the command was skipped by [`Self::before_action`], the
+    /// [`Self::after`] hook is called with the reason.
+    async fn run_before_hooks(&self, ctx: &Context, msg: &Message, name: &str) -> bool {
+        if let Some(before) = &self.before {
+            if !before(ctx, msg, name).await {
+                return false;
+            }
+        }
+
+        if let Some(before_action) = &self.before_action {
+            if let BeforeAction::Skip(reason) = before_action(ctx, msg, name).await {
+                if let Some(after) = &self.after {
+                    after(ctx, msg, name, Err(Box::new(CommandSkipped(reason)))).await;
+                }
+
+                return false;
+            }
+        }
+
+        true
+    }
+
     /// Sets what code should be executed when a user sends `(prefix)help`.
     ///
     /// If a command named `help` in a group was set, then this takes precedence first.
@@ -668,7 +988,7 @@ impl Framework for StandardFramework {
                     return;
                 }
 
-                let args = Args::new(stream.rest(), &self.config.delimiters);
+                let args = self.args(stream.rest(), &self.config.delimiters);
 
                 let owners = self.config.owners.clone();
                 let groups = self.groups.iter().map(|(g, _)| *g).collect::<Vec<_>>();
@@ -677,10 +997,8 @@ impl Framework for StandardFramework {
                 #[allow(clippy::unwrap_used)]
                 let help = self.help.unwrap();
 
-                if let Some(before) = &self.before {
-                    if !before(&mut ctx, &msg, name).await {
-                        return;
-                    }
+                if !self.run_before_hooks(&ctx, &msg, name).await {
+                    return;
                 }
 
                 let res = (help.fun)(&mut ctx, &msg, args, help.options, &groups, owners).await;
@@ -693,32 +1011,7 @@ impl Framework for StandardFramework {
                 command,
                 group,
             } => {
-                let mut args = {
-                    use std::borrow::Cow;
-
-                    let mut delims = Cow::Borrowed(&self.config.delimiters);
-
-                    // If user has configured the command's own delimiters, use those instead.
-                    if !command.options.delimiters.is_empty() {
-                        // FIXME: Get rid of this allocation.
-                        let mut v = Vec::with_capacity(command.options.delimiters.len());
-
-                        for delim in command.options.delimiters {
-                            if delim.len() == 1 {
-                                // Should always be Some() in this case
-                                #[allow(clippy::unwrap_used)]
-                                v.push(Delimiter::Single(delim.chars().next().unwrap()));
-                            } else {
-                                // This too.
-                                v.push(Delimiter::Multiple(delim.to_string()));
-                            }
-                        }
-
-                        delims = Cow::Owned(v);
-                    }
-
-                    Args::new(stream.rest(), &delims)
-                };
+                let mut args = self.args(stream.rest(), &self.delimiters(command));
 
                 if let Some(error) =
                     self.should_fail(&ctx, &msg, &mut args, command.options, group.options).await
@@ -730,38 +1023,105 @@ impl Framework for StandardFramework {
                     return;
                 }
 
-                let name = command.options.names[0];
-
-                if let Some(before) = &self.before {
-                    if !before(&mut ctx, &msg, name).await {
-                        return;
-                    }
-                }
+                self.run_command(&mut ctx, &msg, command, args).await;
+            },
+        }
+    }
 
-                let res = (command.fun)(&mut ctx, &msg, args).await;
+    #[cfg(feature = "unstable_discord_api")]
+    fn handles_interaction(&self, interaction: &Interaction) -> bool {
+        match interaction {
+            Interaction::ApplicationCommand(interaction) => {
+                let data = &interaction.data;
 
-                // Check if the command wants to revert the bucket by giving back a ticket.
-                if matches!(res, Err(ref e) if e.is::<RevertBucket>()) {
-                    let mut buckets = self.buckets.lock().await;
+                self.config.application_commands
+                    && parse::find_application_command(data, &self.groups, &self.config).is_some()
+            },
+            _ => false,
+        }
+    }
 
-                    if let Some(ref mut bucket) =
-                        command.options.bucket.as_ref().and_then(|b| buckets.get_mut(*b))
-                    {
-                        bucket.give(&ctx, &msg).await;
-                    }
-                }
+    #[cfg(feature = "unstable_discord_api")]
+    #[instrument(skip(self, ctx, interaction))]
+    async fn interaction_dispatch(&self, ctx: Context, interaction: Interaction) {
+        if !self.handles_interaction(&interaction) {
+            return;
+        }
 
-                if let Some(after) = &self.after {
-                    after(&mut ctx, &msg, name, res).await;
-                }
-            },
+        if let Interaction::ApplicationCommand(interaction) = interaction {
+            self.dispatch_application_command(ctx, interaction).await;
         }
     }
 }
 
+/// Builds the [`Message`] that commands are given when invoked through an
+/// application command interaction, from the interaction's `response`.
+///
+/// The message keeps the Id of the response, but is authored by the user who
+/// invoked the command.
+#[cfg(feature = "unstable_discord_api")]
+fn application_command_message(
+    interaction: &ApplicationCommandInteraction,
+    response: Message,
+) -> Message {
+    let member = interaction.member.as_ref().map(|member| PartialMember {
+        deaf: member.deaf,
+        joined_at: member.joined_at,
+        mute: member.mute,
+        nick: member.nick.clone(),
+        roles: member.roles.clone(),
+        pending: member.pending,
+        premium_since: member.premium_since,
+        guild_id: Some(member.guild_id),
+        user: Some(member.user.clone()),
+        permissions: member.permissions,
+    });
+
+    Message {
+        author: interaction.user.clone(),
+        content: format!("/{}", interaction.data.name),
+        guild_id: interaction.guild_id,
+        member,
+        interaction: Some(MessageInteraction {
+            id: interaction.id,
+            kind: InteractionType::ApplicationCommand,
+            name: interaction.data.name.clone(),
+            user: interaction.user.clone(),
+        }),
+        ..response
+    }
+}
+
+/// Builds the arguments of a command from the values of application command
+/// options, as if they had been typed out after a prefixed command.
+///
+/// Values containing a delimiter or a quote are quoted, so that they remain a
+/// single argument that [`Args::quoted`] reads back unchanged.
+#[cfg(feature = "unstable_discord_api")]
+fn application_command_args(
+    options: &[ApplicationCommandInteractionDataOption],
+    delimiters: &[Delimiter],
+) -> Args {
+    let values = options
+        .iter()
+        .filter_map(|option| option.value.as_ref())
+        .map(|value| match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    Args::from_values(&values, delimiters)
+}
+
 pub trait CommonOptions {
     fn required_permissions(&self) -> &Permissions;
     fn allowed_roles(&self) -> &'static [&'static str];
+    /// Ids of the roles allowed to use the command, taking precedence over
+    /// [`Self::allowed_roles`] if not empty. Defaults to none.
+    fn allowed_role_ids(&self) -> &'static [RoleId] {
+        &[]
+    }
     fn checks(&self) -> &'static [&'static Check];
     fn only_in(&self) -> OnlyIn;
     fn help_available(&self) -> bool;
@@ -778,6 +1138,10 @@ impl CommonOptions for &GroupOptions {
         self.allowed_roles
     }
 
+    fn allowed_role_ids(&self) -> &'static [RoleId] {
+        self.allowed_role_ids
+    }
+
     fn checks(&self) -> &'static [&'static Check] {
         self.checks
     }
@@ -808,6 +1172,10 @@ impl CommonOptions for &CommandOptions {
         self.allowed_roles
     }
 
+    fn allowed_role_ids(&self) -> &'static [RoleId] {
+        self.allowed_role_ids
+    }
+
     fn checks(&self) -> &'static [&'static Check] {
         self.checks
     }
@@ -870,18 +1238,613 @@ pub(crate) async fn has_correct_permissions(
 }
 
 #[cfg(all(feature = "cache", feature = "http"))]
-pub(crate) fn has_correct_roles(
+pub(crate) async fn has_correct_roles(
+    cache: impl AsRef<Cache>,
     options: &impl CommonOptions,
-    roles: &HashMap<RoleId, Role>,
+    guild_id: GuildId,
     member: &Member,
 ) -> bool {
-    if options.allowed_roles().is_empty() {
+    if !options.allowed_role_ids().is_empty() {
+        options.allowed_role_ids().iter().any(|id| member.roles.contains(id))
+    } else if options.allowed_roles().is_empty() {
         true
     } else {
-        options
-            .allowed_roles()
+        role_ids_by_name(cache, guild_id, options.allowed_roles())
+            .await
             .iter()
-            .flat_map(|r| roles.values().find(|role| *r == role.name))
-            .any(|g| member.roles.contains(&g.id))
+            .any(|id| member.roles.contains(id))
+    }
+}
+
+/// Resolves the `names` of roles in a guild to the Ids of the roles carrying
+/// them, e.g. to turn a command's [`CommandOptions::allowed_roles`] into
+/// [`CommandOptions::allowed_role_ids`].
+///
+/// Multiple roles of the same name are all resolved. The guild's roles are
+/// read from the cache once and their Ids kept by name until its roles change.
+/// A guild missing from the cache resolves to no Ids.
+#[cfg(feature = "cache")]
+pub async fn role_ids_by_name(
+    cache: impl AsRef<Cache>,
+    guild_id: GuildId,
+    names: &[&str],
+) -> Vec<RoleId> {
+    fn resolve(by_name: &HashMap<String, Vec<RoleId>>, names: &[&str]) -> Vec<RoleId> {
+        names.iter().filter_map(|name| by_name.get(*name)).flatten().copied().collect()
+    }
+
+    let cache = cache.as_ref();
+
+    if let Some(by_name) = cache.role_ids_by_name.read().await.get(&guild_id) {
+        return resolve(by_name, names);
+    }
+
+    // Hold the lock while reading the guild, so that a role change clearing
+    // the entry cannot land between reading the roles and storing them.
+    let mut by_guild = cache.role_ids_by_name.write().await;
+
+    if let Some(by_name) = by_guild.get(&guild_id) {
+        return resolve(by_name, names);
+    }
+
+    let by_name = cache
+        .guild_field(guild_id, |guild| {
+            let mut by_name = HashMap::<String, Vec<RoleId>>::new();
+
+            for role in guild.roles.values() {
+                by_name.entry(role.name.clone()).or_default().push(role.id);
+            }
+
+            by_name
+        })
+        .await;
+
+    match by_name {
+        Some(by_name) => resolve(by_guild.entry(guild_id).or_insert(by_name), names),
+        None => Vec::new(),
+    }
+}
+
+/// Checks the number of arguments against the command's `min_args` and
+/// `max_args`. A missing bound is not enforced.
+fn check_argument_count(command: &CommandOptions, args: &Args) -> Option<DispatchError> {
+    if let Some(min) = command.min_args {
+        if args.len() < min as usize {
+            return Some(DispatchError::NotEnoughArguments {
+                min,
+                given: args.len(),
+            });
+        }
+    }
+
+    if let Some(max) = command.max_args {
+        if args.len() > max as usize {
+            return Some(DispatchError::TooManyArguments {
+                max,
+                given: args.len(),
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use futures::future::{BoxFuture, FutureExt};
+    #[cfg(feature = "unstable_discord_api")]
+    use serde_json::json;
+    use typemap_rev::TypeMapKey;
+
+    use super::fixtures::{command, context, group, message};
+    #[cfg(feature = "unstable_discord_api")]
+    use super::{application_command_args, ApplicationCommandInvocation};
+    use super::{
+        check_argument_count,
+        Args,
+        BeforeAction,
+        CommandError,
+        CommandOptions,
+        CommandResult,
+        CommandSkipped,
+        Delimiter,
+        DispatchError,
+        StandardFramework,
+    };
+    use crate::client::Context;
+    use crate::framework::Framework;
+    use crate::model::channel::Message;
+    #[cfg(all(feature = "cache", feature = "http"))]
+    use crate::model::fixtures;
+    #[cfg(feature = "unstable_discord_api")]
+    use crate::{
+        framework::standard::fixtures::context_with,
+        http::mock,
+        model::{
+            id::{MessageId, UserId},
+            interactions::Interaction,
+        },
+    };
+
+    struct CommandRan;
+
+    impl TypeMapKey for CommandRan {
+        type Value = bool;
+    }
+
+    struct SkipReason;
+
+    impl TypeMapKey for SkipReason {
+        type Value = String;
+    }
+
+    struct UnrecognisedName;
+
+    impl TypeMapKey for UnrecognisedName {
+        type Value = String;
+    }
+
+    fn ping<'fut>(ctx: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        async move {
+            ctx.data.write().await.insert::<CommandRan>(true);
+
+            Ok(())
+        }
+        .boxed()
+    }
+
+    struct RawArgs;
+
+    impl TypeMapKey for RawArgs {
+        type Value = Vec<String>;
+    }
+
+    fn record_args<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        args: Args,
+    ) -> BoxFuture<'fut, CommandResult> {
+        async move {
+            let raw = args.raw().map(String::from).collect();
+            ctx.data.write().await.insert::<RawArgs>(raw);
+
+            Ok(())
+        }
+        .boxed()
+    }
+
+    fn skip<'fut>(
+        _: &'fut Context,
+        _: &'fut Message,
+        _: &'fut str,
+    ) -> BoxFuture<'fut, BeforeAction> {
+        async move { BeforeAction::Skip("opted out".to_string()) }.boxed()
+    }
+
+    fn record_skip<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        _: &'fut str,
+        res: Result<(), CommandError>,
+    ) -> BoxFuture<'fut, ()> {
+        async move {
+            if let Err(why) = res {
+                if let Some(CommandSkipped(reason)) = why.downcast_ref::<CommandSkipped>() {
+                    ctx.data.write().await.insert::<SkipReason>(reason.clone());
+                }
+            }
+        }
+        .boxed()
+    }
+
+    fn record_unrecognised<'fut>(
+        ctx: &'fut Context,
+        _: &'fut Message,
+        name: &'fut str,
+    ) -> BoxFuture<'fut, ()> {
+        async move {
+            ctx.data.write().await.insert::<UnrecognisedName>(name.to_string());
+        }
+        .boxed()
+    }
+
+    #[tokio::test]
+    async fn unrecognised_command_needs_prefix() {
+        let group = group(vec![command(&["ping"], ping)]);
+
+        let framework = StandardFramework::new()
+            .configure(|c| c.prefix("!"))
+            .unrecognised_command(record_unrecognised)
+            .group(group);
+
+        let ctx = context();
+        framework.dispatch(ctx.clone(), message("nonsense")).await;
+        assert!(ctx.data.read().await.get::<UnrecognisedName>().is_none());
+
+        framework.dispatch(ctx.clone(), message("!nonsense")).await;
+        assert_eq!(
+            ctx.data.read().await.get::<UnrecognisedName>().map(String::as_str),
+            Some("nonsense")
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_args_are_opt_in() {
+        let group = group(vec![command(&["args"], record_args)]);
+        let framework = StandardFramework::new().configure(|c| c.delimiter(',')).group(group);
+
+        let ctx = context();
+        framework.dispatch(ctx.clone(), message("~args a,,b")).await;
+        assert_eq!(ctx.data.read().await.get::<RawArgs>().unwrap(), &["a", "b"]);
+
+        let framework = framework.configure(|c| c.empty_args(true));
+
+        framework.dispatch(ctx.clone(), message("~args a,,b")).await;
+        assert_eq!(ctx.data.read().await.get::<RawArgs>().unwrap(), &["a", "", "b"]);
+    }
+
+    #[tokio::test]
+    async fn before_action_skip_reaches_after() {
+        let framework = StandardFramework::new().before_action(skip).after(record_skip);
+        let command = command(&["ping"], ping);
+
+        let mut ctx = context();
+        let args = Args::new("", &[Delimiter::Single(' ')]);
+        framework.run_command(&mut ctx, &message("~ping"), command, args).await;
+
+        let data = ctx.data.read().await;
+        assert!(data.get::<CommandRan>().is_none());
+        assert_eq!(data.get::<SkipReason>().map(String::as_str), Some("opted out"));
+    }
+
+    fn argument_count(
+        min_args: Option<u16>,
+        max_args: Option<u16>,
+        args: &str,
+    ) -> Option<DispatchError> {
+        let options = CommandOptions {
+            min_args,
+            max_args,
+            ..Default::default()
+        };
+
+        check_argument_count(&options, &Args::new(args, &[Delimiter::Single(' ')]))
+    }
+
+    #[test]
+    fn argument_count_under_min() {
+        assert!(matches!(
+            argument_count(Some(2), None, "a"),
+            Some(DispatchError::NotEnoughArguments {
+                min: 2,
+                given: 1,
+            })
+        ));
+    }
+
+    #[test]
+    fn argument_count_over_max() {
+        assert!(matches!(
+            argument_count(None, Some(2), "a b c"),
+            Some(DispatchError::TooManyArguments {
+                max: 2,
+                given: 3,
+            })
+        ));
+    }
+
+    #[test]
+    fn argument_count_in_range() {
+        assert!(argument_count(Some(1), Some(3), "a b").is_none());
+        assert!(argument_count(Some(1), None, "a b c d e f").is_none());
+        assert!(argument_count(None, None, "").is_none());
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[test]
+    fn application_command_args_quotes_delimited_values() {
+        let options = serde_json::from_value::<Vec<_>>(json!([
+            {"name": "user", "type": 6, "value": "114941315417899012"},
+            {"name": "reason", "type": 3, "value": "being rude"},
+            {"name": "days", "type": 4, "value": 7},
+            {"name": "silent", "type": 5, "value": true},
+        ]))
+        .expect("valid options");
+
+        let mut args = application_command_args(&options, &[Delimiter::Single(' ')]);
+
+        assert_eq!(args.message(), r#"114941315417899012 "being rude" 7 true"#);
+        assert_eq!(args.single::<u64>().unwrap(), 114941315417899012);
+        assert_eq!(args.single_quoted::<String>().unwrap(), "being rude");
+        assert_eq!(args.single::<u8>().unwrap(), 7);
+        assert!(args.single::<bool>().unwrap());
+        assert!(args.is_empty());
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[test]
+    fn application_command_args_keep_quoted_values() {
+        let options = serde_json::from_value::<Vec<_>>(json!([
+            {"name": "reason", "type": 3, "value": r#"said "hi" twice"#},
+            {"name": "nickname", "type": 3, "value": r#""quoted""#},
+            {"name": "days", "type": 4, "value": 7},
+        ]))
+        .expect("valid options");
+
+        let mut args = application_command_args(&options, &[Delimiter::Single(' ')]);
+
+        assert_eq!(args.len(), 3);
+        assert_eq!(args.single_quoted::<String>().unwrap(), r#"said "hi" twice"#);
+        assert_eq!(args.single_quoted::<String>().unwrap(), r#""quoted""#);
+        assert_eq!(args.single::<u8>().unwrap(), 7);
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    struct Invocation;
+
+    #[cfg(feature = "unstable_discord_api")]
+    impl TypeMapKey for Invocation {
+        type Value = (MessageId, UserId);
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    fn pong<'fut>(
+        ctx: &'fut Context,
+        msg: &'fut Message,
+        _: Args,
+    ) -> BoxFuture<'fut, CommandResult> {
+        async move {
+            ctx.data.write().await.insert::<Invocation>((msg.id, msg.author.id));
+            msg.reply(ctx, "pong").await?;
+
+            Ok(())
+        }
+        .boxed()
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    fn respond<'fut>(
+        ctx: &'fut Context,
+        msg: &'fut Message,
+        _: Args,
+    ) -> BoxFuture<'fut, CommandResult> {
+        async move {
+            let invocation = ApplicationCommandInvocation::of(ctx, msg).unwrap();
+            invocation.respond(ctx, |r| r.content("pong")).await?;
+
+            Ok(())
+        }
+        .boxed()
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    fn fail<'fut>(_: &'fut Context, _: &'fut Message, _: Args) -> BoxFuture<'fut, CommandResult> {
+        async move { Err("no pong".into()) }.boxed()
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    fn respond_with_error<'fut>(
+        ctx: &'fut Context,
+        msg: &'fut Message,
+        _: &'fut str,
+        res: Result<(), CommandError>,
+    ) -> BoxFuture<'fut, ()> {
+        async move {
+            let invocation = ApplicationCommandInvocation::of(ctx, msg);
+
+            if let (Err(why), Some(invocation)) = (res, invocation) {
+                invocation.respond(ctx, |r| r.content(why)).await.unwrap();
+            }
+        }
+        .boxed()
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    fn interaction(name: &str) -> Interaction {
+        serde_json::from_value(json!({
+            "id": "5",
+            "application_id": "1",
+            "type": 2,
+            "data": {"id": "6", "name": name},
+            "channel_id": "2",
+            "user": {
+                "id": "7",
+                "avatar": null,
+                "discriminator": "0001",
+                "username": "user 7",
+            },
+            "token": "token",
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[test]
+    fn handles_application_commands_of_its_commands() {
+        let group = group(vec![command(&["ping"], pong)]);
+        let framework = StandardFramework::new().group(group);
+
+        assert!(!framework.handles_interaction(&interaction("ping")));
+
+        let framework = framework.configure(|c| c.application_commands(true));
+
+        assert!(framework.handles_interaction(&interaction("ping")));
+        assert!(!framework.handles_interaction(&interaction("pong")));
+    }
+
+    #[cfg(feature = "unstable_discord_api")]
+    #[tokio::test]
+    async fn application_command_responses() {
+        let group = group(vec![
+            command(&["ping"], pong),
+            command(&["respond"], respond),
+            command(&["fail"], fail),
+        ]);
+        let framework = StandardFramework::new()
+            .configure(|c| c.application_commands(true))
+            .group(group)
+            .after(respond_with_error);
+
+        let response = serde_json::to_string(&message("")).unwrap();
+
+        // The deferred response is edited into an acknowledgement, which
+        // replies are sent to the channel in reply to.
+        let (proxy, server) = mock::serve(vec![
+            (204, String::new()),
+            (200, response.clone()),
+            (200, response.clone()),
+        ]);
+        let ctx = context_with(mock::http(proxy).await);
+        framework.interaction_dispatch(ctx.clone(), interaction("ping")).await;
+
+        assert_eq!(server.join().unwrap(), vec![
+            "POST /api/v9/interactions/5/token/callback HTTP/1.1",
+            "PATCH /api/v9/webhooks/1/token/messages/@original HTTP/1.1",
+            "POST /api/v9/channels/2/messages HTTP/1.1",
+        ]);
+        assert_eq!(ctx.data.read().await.get::<Invocation>(), Some(&(MessageId(3), UserId(7))));
+
+        // Responding through the invocation sends a follow-up message.
+        let (proxy, server) = mock::serve(vec![
+            (204, String::new()),
+            (200, response.clone()),
+            (200, response.clone()),
+        ]);
+        let ctx = context_with(mock::http(proxy).await);
+        framework.interaction_dispatch(ctx, interaction("respond")).await;
+
+        assert_eq!(server.join().unwrap(), vec![
+            "POST /api/v9/interactions/5/token/callback HTTP/1.1",
+            "PATCH /api/v9/webhooks/1/token/messages/@original HTTP/1.1",
+            "POST /api/v9/webhooks/1/token HTTP/1.1",
+        ]);
+
+        // So does responding from a hook, once the command has failed.
+        let (proxy, server) =
+            mock::serve(vec![(204, String::new()), (200, response.clone()), (200, response)]);
+        let ctx = context_with(mock::http(proxy).await);
+        framework.interaction_dispatch(ctx, interaction("fail")).await;
+
+        assert_eq!(server.join().unwrap(), vec![
+            "POST /api/v9/interactions/5/token/callback HTTP/1.1",
+            "PATCH /api/v9/webhooks/1/token/messages/@original HTTP/1.1",
+            "POST /api/v9/webhooks/1/token HTTP/1.1",
+        ]);
+    }
+
+    #[cfg(all(feature = "cache", feature = "http"))]
+    fn role(id: u64, name: &str) -> crate::model::guild::Role {
+        let mut role = fixtures::role(id, 1);
+        role.name = name.to_string();
+
+        role
+    }
+
+    #[cfg(all(feature = "cache", feature = "http"))]
+    async fn cache_with_roles(roles: Vec<crate::model::guild::Role>) -> crate::cache::Cache {
+        use crate::model::id::GuildId;
+
+        let cache = crate::cache::Cache::default();
+        cache.guilds.write().await.insert(GuildId(1), fixtures::guild(roles, vec![]));
+
+        cache
+    }
+
+    #[cfg(all(feature = "cache", feature = "http"))]
+    #[tokio::test]
+    async fn allowed_role_ids_survive_rename() {
+        use crate::model::id::{GuildId, RoleId};
+
+        let cache = cache_with_roles(vec![role(5, "renamed moderator")]).await;
+        let by_name = CommandOptions {
+            allowed_roles: &["moderator"],
+            ..Default::default()
+        };
+        let by_id = CommandOptions {
+            allowed_roles: &["moderator"],
+            allowed_role_ids: &[RoleId(5)],
+            ..Default::default()
+        };
+        let has_roles = |options, roles| {
+            let cache = &cache;
+
+            async move {
+                super::has_correct_roles(cache, &options, GuildId(1), &fixtures::member(2, roles))
+                    .await
+            }
+        };
+
+        assert!(!has_roles(&by_name, &[5]).await);
+        assert!(has_roles(&by_id, &[5]).await);
+        assert!(!has_roles(&by_id, &[6]).await);
+    }
+
+    #[cfg(all(feature = "cache", feature = "http"))]
+    #[tokio::test]
+    async fn allowed_roles_match_duplicate_names() {
+        use crate::model::id::GuildId;
+
+        let cache =
+            cache_with_roles(vec![role(5, "moderator"), role(6, "moderator"), role(7, "member")])
+                .await;
+        let options = CommandOptions {
+            allowed_roles: &["moderator"],
+            ..Default::default()
+        };
+        let has_roles = |roles| {
+            let (cache, options) = (&cache, &options);
+
+            async move {
+                super::has_correct_roles(cache, &options, GuildId(1), &fixtures::member(2, roles))
+                    .await
+            }
+        };
+
+        assert!(has_roles(&[5]).await);
+        assert!(has_roles(&[6]).await);
+        assert!(!has_roles(&[7]).await);
+    }
+
+    #[cfg(all(feature = "cache", feature = "http"))]
+    #[tokio::test]
+    async fn role_ids_by_name_are_kept_until_roles_change() {
+        use crate::model::event::{GuildRoleDeleteEvent, GuildRoleUpdateEvent};
+        use crate::model::id::{GuildId, RoleId};
+
+        let cache =
+            cache_with_roles(vec![role(5, "moderator"), role(6, "moderator"), role(7, "member")])
+                .await;
+
+        let mut ids = super::role_ids_by_name(&cache, GuildId(1), &["moderator", "admin"]).await;
+        ids.sort();
+        assert_eq!(ids, vec![RoleId(5), RoleId(6)]);
+        assert!(super::role_ids_by_name(&cache, GuildId(2), &["moderator"]).await.is_empty());
+
+        // The mapping is stored, so changing the roles without a role event
+        // does not reach it.
+        cache.guilds.write().await.get_mut(&GuildId(1)).unwrap().roles.remove(&RoleId(5));
+        let mut ids = super::role_ids_by_name(&cache, GuildId(1), &["moderator"]).await;
+        ids.sort();
+        assert_eq!(ids, vec![RoleId(5), RoleId(6)]);
+        assert!(!cache.role_ids_by_name.read().await.contains_key(&GuildId(2)));
+
+        cache
+            .update(&mut GuildRoleUpdateEvent {
+                guild_id: GuildId(1),
+                role: role(7, "admin"),
+            })
+            .await;
+        assert!(!cache.role_ids_by_name.read().await.contains_key(&GuildId(1)));
+        assert_eq!(super::role_ids_by_name(&cache, GuildId(1), &["admin"]).await, vec![RoleId(7)]);
+
+        cache
+            .update(&mut GuildRoleDeleteEvent {
+                guild_id: GuildId(1),
+                role_id: RoleId(7),
+            })
+            .await;
+        assert!(super::role_ids_by_name(&cache, GuildId(1), &["admin"]).await.is_empty());
     }
 }
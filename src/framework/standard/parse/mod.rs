@@ -12,6 +12,13 @@ use map::{CommandMap, GroupMap, ParseMap};
 use tracing::{error, warn};
 use uwl::Stream;
 
+#[cfg(feature = "unstable_discord_api")]
+use crate::model::interactions::application_command::{
+    ApplicationCommandInteractionData,
+    ApplicationCommandInteractionDataOption,
+    ApplicationCommandOptionType,
+};
+
 // FIXME: Add the `http` parameter to `Guild::user_permissions_in`.
 //
 // Trying to shove the parameter to the original method results in several errors
@@ -193,6 +200,14 @@ async fn find_prefix<'a>(
         }
     };
 
+    for f in &config.prefix_matchers {
+        if let Some(len) = f(ctx, msg).await {
+            if let Some(p) = stream.rest().get(..len) {
+                return Some(Cow::Borrowed(p));
+            }
+        }
+    }
+
     for f in &config.dynamic_prefixes {
         if let Some(p) = f(ctx, msg).await {
             let p = to_lowercase(config, &p);
@@ -209,6 +224,7 @@ async fn find_prefix<'a>(
 ///
 /// The "prefix" may be one of the following:
 /// - A mention (`<@id>`/`<@!id>`)
+/// - A prefix matched by a function ([`Configuration::prefix_matcher`])
 /// - A dynamically constructed prefix ([`Configuration::dynamic_prefix`])
 /// - A static prefix ([`Configuration::prefix`])
 /// - Nothing
@@ -286,7 +302,9 @@ async fn check_discrepancy(
                 return Err(DispatchError::LackingPermissions(*options.required_permissions()));
             }
 
-            if !perms.administrator() && !has_correct_roles(options, &roles, &member) {
+            if !perms.administrator()
+                && !has_correct_roles(&ctx.cache, options, guild_id, &member).await
+            {
                 return Err(DispatchError::LackingRole);
             }
         }
@@ -527,8 +545,227 @@ pub async fn command(
     last
 }
 
+/// Finds the command named `name` among the `groups` and their sub-groups,
+/// returning it together with the groups leading up to it.
+#[cfg(feature = "unstable_discord_api")]
+fn find_command(
+    groups: &[&'static CommandGroup],
+    name: &str,
+    config: &Configuration,
+) -> Option<(Vec<&'static CommandGroup>, &'static Command)> {
+    for group in groups {
+        let command = group.options.commands.iter().find(|command| {
+            command.options.names.iter().any(|n| to_lowercase(config, n) == name)
+        });
+
+        if let Some(command) = command {
+            return Some((vec![*group], command));
+        }
+
+        if let Some((mut path, command)) = find_command(group.options.sub_groups, name, config) {
+            path.insert(0, *group);
+
+            return Some((path, command));
+        }
+    }
+
+    None
+}
+
+/// Finds the command an application command interaction names among the
+/// commands of the `groups`, returning it together with the groups leading up
+/// to it.
+#[cfg(feature = "unstable_discord_api")]
+pub fn find_application_command(
+    data: &ApplicationCommandInteractionData,
+    groups: &[(&'static CommandGroup, Map)],
+    config: &Configuration,
+) -> Option<(Vec<&'static CommandGroup>, &'static Command)> {
+    let name = to_lowercase(config, &data.name);
+    let groups = groups.iter().map(|(group, _)| *group).collect::<Vec<_>>();
+
+    find_command(&groups, &name, config)
+}
+
+/// Parse a command from an application command interaction.
+///
+/// The `command` and the groups leading up to it, its `path`, are found with
+/// [`find_application_command`]. Options of the [`SubCommand`] and
+/// [`SubCommandGroup`] types select the sub-command of the same name.
+///
+/// On success, the group owning the command is returned alongside the
+/// (sub-)command and the options that were not consumed in selecting a
+/// sub-command, to be used as the command's arguments.
+///
+/// [`SubCommand`]: ApplicationCommandOptionType::SubCommand
+/// [`SubCommandGroup`]: ApplicationCommandOptionType::SubCommandGroup
+#[cfg(feature = "unstable_discord_api")]
+pub async fn application_command<'a>(
+    ctx: &Context,
+    msg: &Message,
+    data: &'a ApplicationCommandInteractionData,
+    path: &[&'static CommandGroup],
+    mut command: &'static Command,
+    config: &Configuration,
+) -> Result<
+    (&'static CommandGroup, &'static Command, &'a [ApplicationCommandInteractionDataOption]),
+    DispatchError,
+> {
+    let name = to_lowercase(config, &data.name);
+
+    if config.disabled_commands.contains(name.as_ref()) {
+        return Err(DispatchError::CommandDisabled(name.into_owned()));
+    }
+
+    for group in path {
+        check_discrepancy(ctx, msg, config, &group.options).await?;
+    }
+
+    check_discrepancy(ctx, msg, config, &command.options).await?;
+
+    let mut options = data.options.as_slice();
+
+    while let [option] = options {
+        if !matches!(
+            option.kind,
+            ApplicationCommandOptionType::SubCommand
+                | ApplicationCommandOptionType::SubCommandGroup
+        ) {
+            break;
+        }
+
+        let name = to_lowercase(config, &option.name);
+        let sub_command = command
+            .options
+            .sub_commands
+            .iter()
+            .find(|sub| sub.options.names.iter().any(|n| to_lowercase(config, n) == name));
+
+        match sub_command {
+            Some(sub_command) => {
+                check_discrepancy(ctx, msg, config, &sub_command.options).await?;
+
+                command = sub_command;
+                options = option.options.as_slice();
+            },
+            None => break,
+        }
+    }
+
+    // `find_application_command` always returns at least the group owning the
+    // command.
+    #[allow(clippy::unwrap_used)]
+    let group = *path.last().unwrap();
+
+    Ok((group, command, options))
+}
+
 #[derive(Debug)]
 pub enum Invoke {
     Command { group: &'static CommandGroup, command: &'static Command },
     Help(&'static str),
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use uwl::Stream;
+
+    use super::{check_discrepancy, prefix, Configuration};
+    use crate::framework::standard::fixtures::{context, message};
+    use crate::framework::standard::{CommandOptions, DispatchError, GroupOptions, OnlyIn};
+    use crate::model::id::UserId;
+
+    async fn parse_prefix(config: &Configuration, content: &str) -> (Option<String>, String) {
+        let ctx = context();
+        let msg = message(content);
+        let mut stream = Stream::new(&msg.content);
+
+        let prefix = prefix(&ctx, &msg, &mut stream, config).await.map(|p| p.into_owned());
+
+        (prefix, stream.rest().to_string())
+    }
+
+    #[tokio::test]
+    async fn prefix_matcher_consumes_variable_length() {
+        let mut config = Configuration::default();
+        config.on_mention = Some("5".to_string());
+        config.prefix_matcher(|_, msg| {
+            Box::pin(async move {
+                match msg.content.bytes().take_while(|b| *b == b'!').count() {
+                    0 => None,
+                    n => Some(n.min(3)),
+                }
+            })
+        });
+
+        let (prefix, rest) = parse_prefix(&config, "!ping").await;
+        assert_eq!(prefix.as_deref(), Some("!"));
+        assert_eq!(rest, "ping");
+
+        let (prefix, rest) = parse_prefix(&config, "!!!ping").await;
+        assert_eq!(prefix.as_deref(), Some("!!!"));
+        assert_eq!(rest, "ping");
+
+        let (prefix, rest) = parse_prefix(&config, "~ping").await;
+        assert_eq!(prefix.as_deref(), Some("~"));
+        assert_eq!(rest, "ping");
+
+        let (prefix, rest) = parse_prefix(&config, "<@5> ping").await;
+        assert_eq!(prefix.as_deref(), Some("5"));
+        assert_eq!(rest, "ping");
+
+        let (prefix, _) = parse_prefix(&config, "ping").await;
+        assert!(prefix.is_none());
+    }
+
+    #[tokio::test]
+    async fn owners_only_rejects_non_owner() {
+        let ctx = context();
+        let msg = message("ping");
+        let options = CommandOptions {
+            owners_only: true,
+            ..Default::default()
+        };
+
+        let mut config = Configuration::default();
+        config.owners.insert(UserId(1));
+
+        let result = check_discrepancy(&ctx, &msg, &config, &&options).await;
+        assert!(matches!(result, Err(DispatchError::OnlyForOwners)));
+
+        config.owners.insert(msg.author.id);
+
+        assert!(check_discrepancy(&ctx, &msg, &config, &&options).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn guild_only_rejects_dm() {
+        let ctx = context();
+        let msg = message("ping");
+        let config = Configuration::default();
+
+        let options = CommandOptions {
+            only_in: OnlyIn::Guild,
+            ..Default::default()
+        };
+
+        let result = check_discrepancy(&ctx, &msg, &config, &&options).await;
+        assert!(matches!(result, Err(DispatchError::OnlyForGuilds)));
+
+        let options = GroupOptions {
+            only_in: OnlyIn::Guild,
+            ..Default::default()
+        };
+
+        let result = check_discrepancy(&ctx, &msg, &config, &&options).await;
+        assert!(matches!(result, Err(DispatchError::OnlyForGuilds)));
+
+        let options = CommandOptions {
+            only_in: OnlyIn::Dm,
+            ..Default::default()
+        };
+
+        assert!(check_discrepancy(&ctx, &msg, &config, &&options).await.is_ok());
+    }
+}
@@ -4,7 +4,11 @@ use futures::future::BoxFuture;
 
 use super::Args;
 use crate::client::Context;
-use crate::model::{channel::Message, id::UserId, permissions::Permissions};
+use crate::model::{
+    channel::Message,
+    id::{RoleId, UserId},
+    permissions::Permissions,
+};
 use crate::utils::Colour;
 
 pub mod buckets;
@@ -52,6 +56,11 @@ pub struct CommandOptions {
     pub max_args: Option<u16>,
     /// Roles allowed to use this command.
     pub allowed_roles: &'static [&'static str],
+    /// Ids of the roles allowed to use this command.
+    ///
+    /// If not empty, these are used instead of [`Self::allowed_roles`], so
+    /// that renaming a role does not revoke access to the command.
+    pub allowed_role_ids: &'static [RoleId],
     /// Permissions required to use this command.
     pub required_permissions: Permissions,
     /// Whether the command should be displayed in help list or not, used by other commands.
@@ -218,6 +227,7 @@ pub struct GroupOptions {
     pub owner_privilege: bool,
     pub help_available: bool,
     pub allowed_roles: &'static [&'static str],
+    pub allowed_role_ids: &'static [RoleId],
     pub required_permissions: Permissions,
     pub checks: &'static [&'static Check],
     pub default_command: Option<&'static Command>,
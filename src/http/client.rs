@@ -1890,6 +1890,14 @@ impl Http {
                         Part::bytes(picture).file_name(filename.to_string()),
                     );
                 },
+                AttachmentType::Stream {
+                    stream,
+                    filename,
+                } => {
+                    let part = Part::stream(stream.into_body().await?).file_name(filename);
+
+                    multipart = multipart.part(file_num.to_string(), part);
+                },
             }
         }
 
@@ -3050,6 +3058,14 @@ impl Http {
                         Part::bytes(picture).file_name(filename.to_string()),
                     );
                 },
+                AttachmentType::Stream {
+                    stream,
+                    filename,
+                } => {
+                    let part = Part::stream(stream.into_body().await?).file_name(filename);
+
+                    multipart = multipart.part(file_num.to_string(), part);
+                },
             }
         }
 
@@ -0,0 +1,100 @@
+//! A minimal stand-in for Discord's HTTP API, for unit tests that need to
+//! observe the requests made by a method.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+use super::{Http, HttpBuilder};
+
+/// Serves one request for each of the `responses`, in order, answering with
+/// the response's status code and JSON body.
+///
+/// Returns the proxy to pass to [`HttpBuilder::proxy`] and a handle resolving
+/// to the request line of every served request, such as
+/// `GET /api/v9/users/@me HTTP/1.1`.
+pub fn serve(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+    serve_with(responses, |request| request.lines().next().unwrap_or_default().to_string())
+}
+
+/// Like [`serve`], but the handle resolves to every served request in full,
+/// including its headers and body.
+pub fn serve_full(responses: Vec<(u16, String)>) -> (String, JoinHandle<Vec<String>>) {
+    serve_with(responses, ToString::to_string)
+}
+
+fn serve_with(
+    responses: Vec<(u16, String)>,
+    record: fn(&str) -> String,
+) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let proxy = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = thread::spawn(move || {
+        let mut requests = Vec::with_capacity(responses.len());
+
+        for (status, body) in responses {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0; 1024];
+
+            let head = loop {
+                let len = socket.read(&mut buf).unwrap();
+                assert_ne!(len, 0, "connection closed before the request was sent");
+                request.extend_from_slice(&buf[..len]);
+
+                if let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break end + 4;
+                }
+            };
+
+            let headers = String::from_utf8_lossy(&request[..head]).to_lowercase();
+            let length = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map_or(0, |len| len.trim().parse::<usize>().unwrap());
+            let chunked = headers.lines().any(|line| line == "transfer-encoding: chunked");
+
+            while request.len() < head + length || (chunked && !request.ends_with(b"0\r\n\r\n")) {
+                let len = socket.read(&mut buf).unwrap();
+                assert_ne!(len, 0, "connection closed before the body was sent");
+                request.extend_from_slice(&buf[..len]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 {} {}\r\nConnection: close\r\nContent-Type: application/json\r\n\
+                 Content-Length: {}\r\n\r\n{}",
+                status,
+                reason(status),
+                body.len(),
+                body,
+            );
+            socket.write_all(response.as_bytes()).unwrap();
+
+            requests.push(record(&String::from_utf8_lossy(&request)));
+        }
+
+        requests
+    });
+
+    (proxy, server)
+}
+
+/// Builds a client sending its requests to the `proxy` returned by [`serve`].
+pub async fn http(proxy: String) -> Http {
+    let builder = HttpBuilder::new("token").ratelimiter_disabled(true).proxy(proxy).unwrap();
+    #[cfg(feature = "unstable_discord_api")]
+    let builder = builder.application_id(1);
+
+    builder.await.unwrap()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        _ => "Unknown",
+    }
+}
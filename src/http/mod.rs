@@ -25,6 +25,9 @@
 
 pub mod client;
 pub mod error;
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+pub(crate) mod mock;
 pub mod ratelimiting;
 pub mod request;
 pub mod routing;
@@ -33,13 +36,18 @@ pub mod utils;
 
 use std::{
     borrow::Cow,
+    fmt,
     path::{Path, PathBuf},
     sync::Arc,
 };
 
-use reqwest::Method;
 pub use reqwest::StatusCode;
-use tokio::fs::File;
+use reqwest::{Body, Method};
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncReadExt},
+    sync::Mutex,
+};
 
 pub use self::client::*;
 pub use self::error::Error as HttpError;
@@ -52,6 +60,7 @@ use crate::client::Context;
 use crate::model::prelude::*;
 #[cfg(feature = "client")]
 use crate::CacheAndHttp;
+use crate::{Error, Result};
 
 /// This trait will be required by functions that need [`Http`] and can
 /// optionally use a [`Cache`] to potentially avoid REST-requests.
@@ -199,6 +208,9 @@ pub enum AttachmentType<'a> {
     Path(&'a Path),
     /// Indicates that the [`AttachmentType`] is an image URL.
     Image(&'a str),
+    /// Indicates that the [`AttachmentType`] is an [`AttachmentStream`],
+    /// uploaded without reading it into memory first.
+    Stream { stream: AttachmentStream, filename: String },
 }
 
 impl<'a> From<(&'a [u8], &str)> for AttachmentType<'a> {
@@ -243,6 +255,101 @@ impl<'a> From<(&'a File, &str)> for AttachmentType<'a> {
     }
 }
 
+impl<'a> From<(AttachmentStream, &str)> for AttachmentType<'a> {
+    fn from(params: (AttachmentStream, &str)) -> AttachmentType<'a> {
+        AttachmentType::Stream {
+            stream: params.0,
+            filename: params.1.to_string(),
+        }
+    }
+}
+
+/// The size of the chunks an [`AttachmentStream`] is read in.
+const ATTACHMENT_CHUNK_SIZE: usize = 8192;
+
+/// A reader streamed as the contents of an attachment, for files too large to
+/// be held in memory.
+///
+/// To reference the upload in an embed via [`CreateEmbed::attachment`], pass
+/// the same filename to the [`AttachmentType`].
+///
+/// **Note**: The reader can only be uploaded once. Clones share the same
+/// reader, so uploading any of them consumes all of them.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::id::ChannelId;
+/// #
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// # let http = Http::default();
+/// use serenity::http::AttachmentStream;
+/// use tokio::fs::File;
+///
+/// let file = File::open("./large_image.png").await?;
+/// let stream = AttachmentStream::new(file);
+///
+/// ChannelId(7)
+///     .send_files(&http, vec![(stream, "large_image.png")], |m| {
+///         m.embed(|e| e.attachment("large_image.png"))
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`CreateEmbed::attachment`]: crate::builder::CreateEmbed::attachment
+#[derive(Clone)]
+pub struct AttachmentStream(Arc<Mutex<Option<Box<dyn AsyncRead + Send + Unpin>>>>);
+
+impl AttachmentStream {
+    /// Creates a stream uploading the contents of the `reader`.
+    pub fn new(reader: impl AsyncRead + Send + Unpin + 'static) -> Self {
+        AttachmentStream(Arc::new(Mutex::new(Some(Box::new(reader)))))
+    }
+
+    async fn take(&self) -> Result<Box<dyn AsyncRead + Send + Unpin>> {
+        self.0.lock().await.take().ok_or(Error::Other("Attachment stream was already uploaded"))
+    }
+
+    /// Turns the reader into a request body, read in chunks while uploading.
+    pub(crate) async fn into_body(self) -> Result<Body> {
+        let reader = self.take().await?;
+
+        let chunks = futures::stream::unfold(reader, |mut reader| async move {
+            let mut buf = vec![0; ATTACHMENT_CHUNK_SIZE];
+
+            match reader.read(&mut buf).await {
+                Ok(0) => None,
+                Ok(len) => {
+                    buf.truncate(len);
+
+                    Some((Ok(buf), reader))
+                },
+                Err(why) => Some((Err(why), reader)),
+            }
+        });
+
+        Ok(Body::wrap_stream(chunks))
+    }
+
+    /// Reads the whole reader into memory.
+    pub(crate) async fn read_to_end(self) -> Result<Vec<u8>> {
+        let mut reader = self.take().await?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+
+        Ok(buf)
+    }
+}
+
+impl fmt::Debug for AttachmentStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AttachmentStream").finish()
+    }
+}
+
 /// Representation of the method of a query to send for the [`get_guilds`]
 /// function.
 ///
@@ -256,10 +363,11 @@ pub enum GuildPagination {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod test {
     use std::path::Path;
 
-    use super::AttachmentType;
+    use super::{mock, AttachmentStream, AttachmentType};
 
     #[test]
     fn test_attachment_type() {
@@ -272,4 +380,23 @@ mod test {
             AttachmentType::Path(_)
         ));
     }
+
+    #[tokio::test]
+    async fn test_attachment_stream() {
+        let (proxy, server) = mock::serve_full(vec![(400, "{}".to_string())]);
+        let http = mock::http(proxy).await;
+
+        let stream = AttachmentStream::new(&b"streamed contents"[..]);
+        let result =
+            http.send_files(7, vec![(stream.clone(), "image.png")], Default::default()).await;
+        assert!(result.is_err());
+
+        let request = server.join().unwrap().remove(0);
+        assert!(request.starts_with("POST /api/v9/channels/7/messages HTTP/1.1"));
+        assert!(request.contains(r#"name="0"; filename="image.png""#));
+        assert!(request.contains("streamed contents"));
+
+        // The reader was consumed by the upload.
+        assert!(stream.read_to_end().await.is_err());
+    }
 }
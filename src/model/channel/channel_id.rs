@@ -705,6 +705,9 @@ impl ChannelId {
     /// [`ModelError::MessageTooLong`] will be returned, containing the number
     /// of unicode code points over the limit.
     ///
+    /// Returns a [`ModelError::EmptyMessage`] if no files are given and the
+    /// message has neither content nor embeds.
+    ///
     /// Returns an
     /// [`HttpError::UnsuccessfulRequest(ErrorResponse)`][`HttpError::UnsuccessfulRequest`]
     /// if the file(s) are too large to send.
@@ -730,9 +733,11 @@ impl ChannelId {
         let msg = f(&mut create_message);
 
         let map = utils::hashmap_to_json_map(msg.0.clone());
+        let files = files.into_iter().collect::<Vec<_>>();
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_empty(&map, !files.is_empty())?;
 
         http.as_ref().send_files(self.0, files, map).await
     }
@@ -752,10 +757,17 @@ impl ChannelId {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns an error if an embed exceeds one of Discord's limits, as checked
+    /// by [`CreateEmbed::validate`].
+    ///
+    /// Returns a [`ModelError::EmptyMessage`] if the message has no content,
+    /// embeds, or files.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission to
     /// send a message in this channel.
     ///
     /// [`CreateMessage`]: crate::builder::CreateMessage
+    /// [`CreateEmbed::validate`]: crate::builder::CreateEmbed::validate
     /// [Send Messages]: Permissions::SEND_MESSAGES
     #[cfg(feature = "utils")]
     pub async fn send_message<'a, F>(self, http: impl AsRef<Http>, f: F) -> Result<Message>
@@ -769,6 +781,7 @@ impl ChannelId {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_empty(&map, !msg.2.is_empty())?;
 
         let message = if msg.2.is_empty() {
             http.as_ref().send_message(self.0, &Value::Object(map)).await?
@@ -922,6 +935,14 @@ impl ChannelId {
 
                 "data:image/png;base64,".to_string() + &base64::encode(&picture)
             },
+            AttachmentType::Stream {
+                stream,
+                filename: _,
+            } => {
+                let buf = stream.read_to_end().await?;
+
+                "data:image/png;base64,".to_string() + &base64::encode(&buf)
+            },
         };
 
         let map = serde_json::json!({
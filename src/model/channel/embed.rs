@@ -14,7 +14,7 @@ use crate::utils::Colour;
 /// a webhook.
 ///
 /// **Note**: Maximum amount of characters you can put is 256 in a field name,
-/// 1024 in a field value, and 2048 in a description.
+/// 1024 in a field value, and 4096 in a description.
 ///
 /// [slack's attachments]: https://api.slack.com/docs/message-attachments
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -32,7 +32,7 @@ pub struct Embed {
     pub colour: u32,
     /// The description of the embed.
     ///
-    /// The maximum value for this field is 2048 unicode codepoints.
+    /// The maximum value for this field is 4096 unicode codepoints.
     pub description: Option<String>,
     /// The array of fields.
     ///
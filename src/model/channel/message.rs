@@ -17,7 +17,7 @@ use serde::{
 use serde_json::Value;
 
 #[cfg(all(feature = "model", feature = "utils"))]
-use crate::builder::{CreateEmbed, EditMessage};
+use crate::builder::{create_embed, CreateEmbed, EditMessage};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "collector")]
@@ -931,6 +931,27 @@ impl Message {
         Ok(())
     }
 
+    pub(crate) fn check_empty(map: &JsonMap, has_files: bool) -> Result<()> {
+        let has_content = match map.get("content") {
+            Some(Value::String(content)) => !content.is_empty(),
+            _ => false,
+        };
+        let has_embeds = match map.get("embeds") {
+            Some(Value::Array(embeds)) => !embeds.is_empty(),
+            _ => false,
+        };
+        let has_stickers = match map.get("sticker_ids") {
+            Some(Value::Array(sticker_ids)) => !sticker_ids.is_empty(),
+            _ => false,
+        };
+
+        if has_content || has_embeds || has_stickers || has_files {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::EmptyMessage))
+        }
+    }
+
     pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
         let embeds = match map.get("embeds") {
             Some(&Value::Array(ref value)) => value,
@@ -942,6 +963,8 @@ impl Message {
         }
 
         for embed in embeds {
+            create_embed::check_limits(|key| embed.get(key))?;
+
             let mut total: usize = 0;
 
             if let Some(&Value::Object(ref author)) = embed.get("author") {
@@ -1281,3 +1304,86 @@ impl MessageId {
         self.link(channel_id, guild_id)
     }
 }
+
+#[cfg(all(test, feature = "model", feature = "utils"))]
+mod test {
+    use serde_json::json;
+
+    use super::Message;
+    use crate::builder::CreateMessage;
+    use crate::model::ModelError;
+    use crate::utils;
+    use crate::Error;
+
+    #[test]
+    fn check_empty() {
+        let mut builder = CreateMessage::default();
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(matches!(
+            Message::check_empty(&map, false),
+            Err(Error::Model(ModelError::EmptyMessage))
+        ));
+        assert!(Message::check_empty(&map, true).is_ok());
+
+        builder.content("");
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(matches!(
+            Message::check_empty(&map, false),
+            Err(Error::Model(ModelError::EmptyMessage))
+        ));
+
+        builder.embed(|e| e.title("title"));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_empty(&map, false).is_ok());
+
+        let mut builder = CreateMessage::default();
+        builder.content("content");
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_empty(&map, false).is_ok());
+
+        let mut builder = CreateMessage::default();
+        builder.0.insert("sticker_ids", json!([]));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(matches!(
+            Message::check_empty(&map, false),
+            Err(Error::Model(ModelError::EmptyMessage))
+        ));
+
+        builder.0.insert("sticker_ids", json!(["749054660769218631"]));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_empty(&map, false).is_ok());
+    }
+
+    #[test]
+    fn check_embed_length() {
+        let mut builder = CreateMessage::default();
+        builder.embed(|e| e.description("a".repeat(4096)));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(Message::check_embed_length(&map).is_ok());
+
+        builder.embed(|e| e.description("a".repeat(4097)));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(matches!(
+            Message::check_embed_length(&map),
+            Err(Error::Model(ModelError::EmbedValueTooLong("description", 1)))
+        ));
+
+        // The amount of fields isn't limited when building the embed, only when
+        // sending it.
+        builder.embed(|e| e.fields((0..26).map(|i| (i, i, false))));
+        let map = utils::hashmap_to_json_map(builder.0.clone());
+
+        assert!(matches!(
+            Message::check_embed_length(&map),
+            Err(Error::Model(ModelError::EmbedFieldAmount(26)))
+        ));
+    }
+}
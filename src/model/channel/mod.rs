@@ -531,6 +531,9 @@ mod test {
                     discriminator: 1,
                     name: "ab".to_string(),
                     public_flags: None,
+                    banner: None,
+                    accent_colour: None,
+                    avatar_decoration: None,
                 },
             }
         }
@@ -525,6 +525,16 @@ impl From<Emoji> for ReactionType {
     }
 }
 
+impl<'a> From<&'a Emoji> for ReactionType {
+    fn from(emoji: &'a Emoji) -> ReactionType {
+        ReactionType::Custom {
+            animated: emoji.animated,
+            id: emoji.id,
+            name: Some(emoji.name.clone()),
+        }
+    }
+}
+
 impl From<EmojiId> for ReactionType {
     fn from(emoji_id: EmojiId) -> ReactionType {
         ReactionType::Custom {
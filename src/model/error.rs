@@ -74,6 +74,24 @@ pub enum Error {
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(usize),
+    /// When attempting to send a message without content, embeds, stickers, or
+    /// files.
+    EmptyMessage,
+    /// When attempting to set over 100 permission overwrites on an application
+    /// command.
+    ///
+    /// The number of permission overwrites is provided.
+    CommandPermissionAmount(usize),
+    /// When attempting to send an embed with over 25 fields.
+    ///
+    /// The number of fields in the embed is provided.
+    EmbedFieldAmount(usize),
+    /// Indicates that a single value of an embed, such as its title or one of
+    /// its field values, exceeds that value's maximum length.
+    ///
+    /// The name of the value and the number of code points over the limit are
+    /// provided.
+    EmbedValueTooLong(&'static str, usize),
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -166,6 +184,23 @@ pub enum Error {
     NoTokenSet,
     /// Indicates that the component type cannot be used in this context.
     InvalidComponentType,
+    /// Indicates that an [`Emoji`] is managed by an integration and cannot be
+    /// edited.
+    ///
+    /// [`Emoji`]: super::guild::Emoji
+    EmojiManaged,
+    /// Indicates that a builder is missing a field that Discord requires.
+    ///
+    /// The name of the missing field is provided.
+    MissingField(&'static str),
+    /// Indicates that a timestamp is not in a valid ISO-8601 format.
+    ///
+    /// The rejected timestamp is provided.
+    InvalidTimestamp(String),
+    /// Indicates that a colour is not a valid hexadecimal `RRGGBB` value.
+    ///
+    /// The rejected colour is provided.
+    InvalidColour(String),
 }
 
 impl Error {
@@ -190,6 +225,16 @@ impl Display for Error {
             Error::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
             Error::EmbedAmount => f.write_str("Too many embeds in a message."),
             Error::EmbedTooLarge(_) => f.write_str("Embed too large."),
+            Error::EmptyMessage => {
+                f.write_str("Message has no content, embeds, stickers, or files.")
+            },
+            Error::CommandPermissionAmount(_) => {
+                f.write_str("Too many permissions for an application command.")
+            },
+            Error::EmbedFieldAmount(_) => f.write_str("Too many fields in an embed."),
+            Error::EmbedValueTooLong(..) => f.write_str("Embed value too long."),
+            Error::EmojiManaged => f.write_str("Managed emojis cannot be edited."),
+            Error::MissingField(_) => f.write_str("A required field is missing."),
             Error::GuildNotFound => f.write_str("Guild not found in the cache."),
             Error::RoleNotFound => f.write_str("Role not found in the cache."),
             Error::MemberNotFound => f.write_str("Member not found in the cache."),
@@ -198,6 +243,8 @@ impl Display for Error {
             Error::InvalidChannelType => f.write_str("The channel cannot perform the action."),
             Error::InvalidComponentType => f.write_str("The component cannot perform this action."),
             Error::InvalidPermissions(_) => f.write_str("Invalid permissions."),
+            Error::InvalidTimestamp(_) => f.write_str("Invalid ISO-8601 timestamp."),
+            Error::InvalidColour(_) => f.write_str("Invalid hexadecimal colour."),
             Error::InvalidUser => f.write_str("The current user cannot perform the action."),
             Error::ItemMissing => f.write_str("The required item is missing from the cache."),
             Error::WrongGuild => f.write_str("Provided member or channel is from the wrong guild."),
@@ -295,6 +295,8 @@ impl CacheUpdate for GuildCreateEvent {
 
         cache.channels.write().await.extend(guild.channels.clone().into_iter());
         cache.guilds.write().await.insert(self.guild.id, guild);
+        #[cfg(feature = "framework")]
+        cache.role_ids_by_name.write().await.remove(&self.guild.id);
 
         None
     }
@@ -329,7 +331,11 @@ impl CacheUpdate for GuildDeleteEvent {
     type Output = Guild;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        match cache.guilds.write().await.remove(&self.guild.id) {
+        let guild = cache.guilds.write().await.remove(&self.guild.id);
+        #[cfg(feature = "framework")]
+        cache.role_ids_by_name.write().await.remove(&self.guild.id);
+
+        match guild {
             Some(guild) => {
                 for channel_id in guild.channels.keys() {
                     // Remove the channel from the cache.
@@ -634,6 +640,8 @@ impl CacheUpdate for GuildRoleCreateEvent {
             .await
             .get_mut(&self.guild_id)
             .map(|g| g.roles.insert(self.role.id, self.role.clone()));
+        #[cfg(feature = "framework")]
+        cache.role_ids_by_name.write().await.remove(&self.guild_id);
 
         None
     }
@@ -683,12 +691,16 @@ impl CacheUpdate for GuildRoleDeleteEvent {
     type Output = Role;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        cache
+        let role = cache
             .guilds
             .write()
             .await
             .get_mut(&self.guild_id)
-            .and_then(|g| g.roles.remove(&self.role_id))
+            .and_then(|g| g.roles.remove(&self.role_id));
+        #[cfg(feature = "framework")]
+        cache.role_ids_by_name.write().await.remove(&self.guild_id);
+
+        role
     }
 }
 
@@ -705,13 +717,17 @@ impl CacheUpdate for GuildRoleUpdateEvent {
     type Output = Role;
 
     async fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(guild) = cache.guilds.write().await.get_mut(&self.guild_id) {
-            if let Some(role) = guild.roles.get_mut(&self.role.id) {
-                return Some(mem::replace(role, self.role.clone()));
-            }
-        }
+        let old = cache
+            .guilds
+            .write()
+            .await
+            .get_mut(&self.guild_id)
+            .and_then(|guild| guild.roles.get_mut(&self.role.id))
+            .map(|role| mem::replace(role, self.role.clone()));
+        #[cfg(feature = "framework")]
+        cache.role_ids_by_name.write().await.remove(&self.guild_id);
 
-        None
+        old
     }
 }
 
@@ -833,6 +849,8 @@ impl CacheUpdate for GuildUpdateEvent {
             guild.widget_channel_id = self.guild.widget_channel_id;
             guild.widget_enabled = self.guild.widget_enabled;
         }
+        #[cfg(feature = "framework")]
+        cache.role_ids_by_name.write().await.remove(&self.guild.id);
 
         None
     }
@@ -1389,6 +1407,30 @@ pub struct InteractionCreateEvent {
     pub interaction: Interaction,
 }
 
+#[cfg(all(feature = "cache", feature = "unstable_discord_api"))]
+#[async_trait]
+impl CacheUpdate for InteractionCreateEvent {
+    type Output = ();
+
+    async fn update(&mut self, cache: &Cache) -> Option<()> {
+        // The member invoking the interaction is sent along with it, with their
+        // current roles and nickname.
+        let member = match &self.interaction {
+            Interaction::Ping(_) => None,
+            Interaction::ApplicationCommand(interaction) => interaction.member.as_ref(),
+            Interaction::MessageComponent(interaction) => interaction.member.as_ref(),
+        }?;
+
+        cache.update_user_entry(&member.user).await;
+
+        if let Some(guild) = cache.guilds.write().await.get_mut(&member.guild_id) {
+            guild.members.insert(member.user.id, member.clone());
+        }
+
+        None
+    }
+}
+
 #[cfg(feature = "unstable_discord_api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
 impl<'de> Deserialize<'de> for InteractionCreateEvent {
@@ -0,0 +1,95 @@
+//! Minimal models shared by the unit tests of the crate.
+//!
+//! The models are deserialized from the smallest payload Discord could send,
+//! so that tests only spell out the fields they care about and mutate the
+//! returned values as needed.
+
+use serde_json::json;
+
+use super::prelude::*;
+
+/// Builds an uncoloured, non-hoisted role without permissions in the guild
+/// with an Id of `1`.
+pub fn role(id: u64, position: i64) -> Role {
+    serde_json::from_value(json!({
+        "id": id.to_string(),
+        "guild_id": "1",
+        "color": 0,
+        "hoist": false,
+        "managed": false,
+        "name": format!("role {}", id),
+        "permissions": "0",
+        "position": position,
+    }))
+    .expect("valid role")
+}
+
+/// Builds a member of the guild with an Id of `1`, holding the given roles.
+pub fn member(user_id: u64, roles: &[u64]) -> Member {
+    serde_json::from_value(json!({
+        "deaf": false,
+        "guild_id": "1",
+        "joined_at": null,
+        "mute": false,
+        "roles": roles.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        "user": {
+            "id": user_id.to_string(),
+            "avatar": null,
+            "discriminator": "0001",
+            "username": format!("user {}", user_id),
+        },
+    }))
+    .expect("valid member")
+}
+
+/// Builds a text channel without permission overwrites in the guild with an
+/// Id of `1`.
+pub fn channel(id: u64) -> GuildChannel {
+    serde_json::from_value(json!({
+        "id": id.to_string(),
+        "guild_id": "1",
+        "name": format!("channel {}", id),
+        "nsfw": false,
+        "permission_overwrites": [],
+        "position": 0,
+        "type": 0,
+    }))
+    .expect("valid channel")
+}
+
+/// Builds a guild with an Id of `1` and an owner with an Id of `210`,
+/// containing the given roles and members.
+pub fn guild(roles: Vec<Role>, members: Vec<Member>) -> Guild {
+    let mut guild: Guild = serde_json::from_value(json!({
+        "afk_timeout": 0,
+        "channels": [],
+        "default_message_notifications": 0,
+        "emojis": [],
+        "explicit_content_filter": 0,
+        "features": [],
+        "id": "1",
+        "joined_at": "2016-04-30T11:18:25.796Z",
+        "large": false,
+        "member_count": 0,
+        "members": [],
+        "mfa_level": 0,
+        "name": "Spaghetti",
+        "nsfw": false,
+        "nsfw_level": 0,
+        "owner_id": "210",
+        "presences": [],
+        "region": "NA",
+        "roles": [],
+        "system_channel_flags": 0,
+        "verification_level": 0,
+        "voice_states": [],
+        "preferred_locale": "en-US",
+    }))
+    .expect("valid guild");
+
+    guild.member_count = members.len() as u64;
+    guild.roles = roles.into_iter().map(|role| (role.id, role)).collect();
+    guild.members = members.into_iter().map(|member| (member.user.id, member)).collect();
+
+    guild
+}
@@ -1,19 +1,19 @@
 use std::fmt::{Display, Formatter, Result as FmtResult, Write as FmtWrite};
 
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use serde_json::json;
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 use crate::http::Http;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::model::id::GuildId;
 use crate::model::id::{EmojiId, RoleId};
 use crate::model::user::User;
 use crate::model::utils::default_true;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::model::ModelError;
 
 /// Represents a custom guild emoji, which can either be created using the API,
@@ -109,6 +109,28 @@ impl Emoji {
         }
     }
 
+    /// Deletes the emoji from the given guild.
+    ///
+    /// Unlike [`Self::delete`], this does not need the cache to find the guild
+    /// owning the emoji.
+    ///
+    /// **Note**: The [Manage Emojis] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the
+    /// emoji does not belong to the guild.
+    ///
+    /// [Manage Emojis]: crate::model::permissions::Permissions::MANAGE_EMOJIS
+    #[inline]
+    pub async fn delete_in(
+        &self,
+        http: impl AsRef<Http>,
+        guild_id: impl Into<GuildId>,
+    ) -> Result<()> {
+        guild_id.into().delete_emoji(http, self.id).await
+    }
+
     /// Edits the emoji by updating it with a new name.
     /// This method requires the cache to fetch the guild ID.
     ///
@@ -116,6 +138,9 @@ impl Emoji {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::EmojiManaged`] if the emoji is managed by an
+    /// integration.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission,
     /// or if an invalid name is given.
     ///
@@ -126,12 +151,10 @@ impl Emoji {
         cache_http: T,
         name: &str,
     ) -> Result<()> {
+        let map = self.edit_map(name, None)?;
+
         match self.find_guild_id(&cache_http).await {
             Some(guild_id) => {
-                let map = json!({
-                    "name": name,
-                });
-
                 *self = AsRef::<Http>::as_ref(&cache_http)
                     .edit_emoji(guild_id.0, self.id.0, &map)
                     .await?;
@@ -142,6 +165,52 @@ impl Emoji {
         }
     }
 
+    /// Edits the emoji's name and the roles allowed to use it in the given
+    /// guild, updating the emoji in-place.
+    ///
+    /// An empty list of `roles` makes the emoji usable by everyone.
+    ///
+    /// **Note**: The [Manage Emojis] permission is required.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmojiManaged`] if the emoji is managed by an
+    /// integration.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if an invalid name is given.
+    ///
+    /// [Manage Emojis]: crate::model::permissions::Permissions::MANAGE_EMOJIS
+    pub async fn edit_in(
+        &mut self,
+        http: impl AsRef<Http>,
+        guild_id: impl Into<GuildId>,
+        name: &str,
+        roles: &[RoleId],
+    ) -> Result<()> {
+        let map = self.edit_map(name, Some(roles))?;
+
+        *self = http.as_ref().edit_emoji(guild_id.into().0, self.id.0, &map).await?;
+
+        Ok(())
+    }
+
+    fn edit_map(&self, name: &str, roles: Option<&[RoleId]>) -> Result<Value> {
+        if self.managed {
+            return Err(Error::Model(ModelError::EmojiManaged));
+        }
+
+        let mut map = json!({
+            "name": name,
+        });
+
+        if let (Some(roles), Value::Object(map)) = (roles, &mut map) {
+            map.insert("roles".to_string(), json!(roles));
+        }
+
+        Ok(map)
+    }
+
     /// Finds the [`Guild`] that owns the emoji by looking through the Cache.
     ///
     /// [`Guild`]: super::Guild
@@ -185,6 +254,13 @@ impl Emoji {
         None
     }
 
+    /// Whether a member with the given roles may use the emoji.
+    ///
+    /// An emoji without any [`Self::roles`] can be used by everyone.
+    pub fn is_usable_by(&self, member_roles: &[RoleId]) -> bool {
+        self.roles.is_empty() || self.roles.iter().any(|role| member_roles.contains(role))
+    }
+
     /// Generates a URL to the emoji's image.
     ///
     /// # Examples
@@ -217,6 +293,58 @@ impl Emoji {
         let extension = if self.animated { "gif" } else { "png" };
         format!(cdn!("/emojis/{}.{}"), self.id, extension)
     }
+
+    /// Formats the emoji in the `NAME:EMOJI_ID` form expected by the reaction
+    /// endpoints, for both normal and animated emojis.
+    ///
+    /// **Note**: This differs from the [`Display`] implementation, which
+    /// produces the `<:NAME:EMOJI_ID>` form used in message content and is
+    /// rejected by the reaction endpoints. To react with an emoji, it can also
+    /// be converted into a [`ReactionType`].
+    ///
+    /// [`ReactionType`]: crate::model::channel::ReactionType
+    #[inline]
+    pub fn reaction_string(&self) -> String {
+        format!("{}:{}", self.name, self.id)
+    }
+}
+
+#[cfg(feature = "utils")]
+impl Emoji {
+    /// Parses an emoji from its mention, in the `<:NAME:EMOJI_ID>` form, or
+    /// `<a:NAME:EMOJI_ID>` for animated emojis.
+    ///
+    /// Only the animated state, name and Id are known from the mention; every
+    /// other field is left at its default.
+    ///
+    /// If the mention is invalid, then [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::guild::Emoji;
+    /// use serenity::model::id::EmojiId;
+    ///
+    /// let emoji = Emoji::from_mention("<a:blobdance:302516740095606785>").unwrap();
+    ///
+    /// assert!(emoji.animated);
+    /// assert_eq!(emoji.name, "blobdance");
+    /// assert_eq!(emoji.id, EmojiId(302516740095606785));
+    /// ```
+    pub fn from_mention(mention: &str) -> Option<Self> {
+        let identifier = crate::utils::parse_emoji(mention)?;
+
+        Some(Emoji {
+            animated: identifier.animated,
+            available: true,
+            id: identifier.id,
+            name: identifier.name,
+            managed: false,
+            require_colons: false,
+            roles: Vec::new(),
+            user: None,
+        })
+    }
 }
 
 impl Display for Emoji {
@@ -251,3 +379,133 @@ impl<'a> From<&'a Emoji> for EmojiId {
         emoji.id
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+#[allow(clippy::unwrap_used)]
+mod test {
+    use serde_json::json;
+
+    use super::Emoji;
+    use crate::model::id::RoleId;
+    use crate::model::ModelError;
+    use crate::Error;
+
+    fn emoji(managed: bool) -> Emoji {
+        serde_json::from_value(json!({
+            "animated": false,
+            "id": "7",
+            "name": "blobface",
+            "managed": managed,
+            "require_colons": false,
+            "roles": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn edit_map() {
+        let emoji = emoji(false);
+
+        assert_eq!(emoji.edit_map("blobsmile", None).unwrap(), json!({"name": "blobsmile"}));
+        assert_eq!(
+            emoji.edit_map("blobsmile", Some(&[RoleId(1), RoleId(2)])).unwrap(),
+            json!({"name": "blobsmile", "roles": [1, 2]})
+        );
+    }
+
+    #[test]
+    fn edit_managed() {
+        let emoji = emoji(true);
+
+        assert!(matches!(
+            emoji.edit_map("blobsmile", Some(&[])),
+            Err(Error::Model(ModelError::EmojiManaged))
+        ));
+    }
+
+    #[test]
+    fn is_usable_by() {
+        let mut emoji = emoji(false);
+
+        assert!(emoji.is_usable_by(&[]));
+        assert!(emoji.is_usable_by(&[RoleId(1)]));
+
+        emoji.roles = vec![RoleId(1), RoleId(2)];
+
+        assert!(emoji.is_usable_by(&[RoleId(3), RoleId(2)]));
+        assert!(!emoji.is_usable_by(&[RoleId(3)]));
+        assert!(!emoji.is_usable_by(&[]));
+    }
+
+    #[test]
+    fn display() {
+        let mut emoji = emoji(false);
+        assert_eq!(emoji.to_string(), "<:blobface:7>");
+
+        emoji.animated = true;
+        assert_eq!(emoji.to_string(), "<a:blobface:7>");
+    }
+
+    #[test]
+    fn reaction_string() {
+        let mut emoji = emoji(false);
+        assert_eq!(emoji.reaction_string(), "blobface:7");
+
+        emoji.animated = true;
+        assert_eq!(emoji.reaction_string(), "blobface:7");
+    }
+
+    #[cfg(feature = "utils")]
+    #[test]
+    fn from_mention() {
+        use crate::model::id::EmojiId;
+
+        let emoji = Emoji::from_mention("<a:blobface:7>").unwrap();
+        assert!(emoji.animated);
+        assert_eq!(emoji.name, "blobface");
+        assert_eq!(emoji.id, EmojiId(7));
+        assert!(!emoji.managed);
+        assert!(emoji.roles.is_empty());
+
+        let emoji = Emoji::from_mention("<:blobface:7>").unwrap();
+        assert!(!emoji.animated);
+        assert_eq!(emoji.to_string(), "<:blobface:7>");
+
+        assert!(Emoji::from_mention("<:blobface:7").is_none());
+        assert!(Emoji::from_mention("<:blobface:abc>").is_none());
+        assert!(Emoji::from_mention(":blobface:").is_none());
+        assert!(Emoji::from_mention("<\u{e9}:blobface:7>").is_none());
+        assert!(Emoji::from_mention("").is_none());
+    }
+
+    #[test]
+    fn deserialize_mixed_list() {
+        let emojis: Vec<Emoji> = serde_json::from_value(json!([
+            {"animated": true, "id": "7", "name": "blobdance", "roles": []},
+            {"animated": false, "id": "8", "name": "blobface", "roles": []},
+            {"id": "9", "name": "blobsmile", "roles": []},
+        ]))
+        .unwrap();
+
+        let animated = emojis.iter().map(|emoji| emoji.animated).collect::<Vec<_>>();
+        assert_eq!(animated, [true, false, false]);
+    }
+
+    #[test]
+    fn into_reaction_type() {
+        use crate::model::channel::ReactionType;
+        use crate::model::id::EmojiId;
+
+        let mut emoji = emoji(false);
+        emoji.animated = true;
+
+        let expected = ReactionType::Custom {
+            animated: true,
+            id: EmojiId(7),
+            name: Some("blobface".to_string()),
+        };
+
+        assert_eq!(ReactionType::from(&emoji), expected);
+        assert_eq!(ReactionType::from(emoji), expected);
+    }
+}
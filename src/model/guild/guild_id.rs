@@ -1,5 +1,10 @@
+#[cfg(feature = "model")]
+use std::collections::HashSet;
+
 use futures::stream::Stream;
 #[cfg(feature = "model")]
+use reqwest::StatusCode;
+#[cfg(feature = "model")]
 use serde_json::json;
 
 #[cfg(feature = "model")]
@@ -35,6 +40,11 @@ use crate::{
     model::interactions::application_command::{ApplicationCommand, ApplicationCommandPermission},
 };
 
+/// The most uncached members [`GuildId::members_roles`] fetches, with one
+/// request each.
+#[cfg(feature = "model")]
+const MEMBERS_ROLES_REQUEST_LIMIT: usize = 10;
+
 #[cfg(feature = "model")]
 impl GuildId {
     /// Ban a [`User`] from the guild, deleting a number of
@@ -788,6 +798,65 @@ impl GuildId {
         cache_http.http().get_member(self.0, user_id.0).await
     }
 
+    /// Gets the roles of several users in the guild.
+    ///
+    /// If the cache feature is enabled the cache will be checked first, so
+    /// that only the users not found in it are fetched over the REST API, with
+    /// one request each. At most 10 users are fetched this way, in the order
+    /// they are given; the rest are returned alongside the roles as
+    /// unresolved. Request the guild's members over the gateway with
+    /// [`Shard::chunk_guild`] to resolve more from the cache.
+    ///
+    /// Users who are not members of the guild are left out of the returned
+    /// map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if fetching a member fails for any reason
+    /// other than the user not being in the guild.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Shard::chunk_guild`]: crate::gateway::Shard::chunk_guild
+    pub async fn members_roles(
+        self,
+        cache_http: impl CacheHttp,
+        user_ids: &[UserId],
+    ) -> Result<(HashMap<UserId, Vec<RoleId>>, Vec<UserId>)> {
+        #[allow(unused_mut)]
+        let mut roles = HashMap::with_capacity(user_ids.len());
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(cached) =
+                    cache.guild_field(self, |guild| guild.members_roles(user_ids)).await
+                {
+                    roles = cached;
+                }
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut missing = user_ids
+            .iter()
+            .copied()
+            .filter(|id| !roles.contains_key(id) && seen.insert(*id))
+            .collect::<Vec<_>>();
+        let unresolved = missing.split_off(missing.len().min(MEMBERS_ROLES_REQUEST_LIMIT));
+
+        for user_id in missing {
+            match cache_http.http().get_member(self.0, user_id.0).await {
+                Ok(member) => {
+                    roles.insert(user_id, member.roles);
+                },
+                Err(Error::Http(why)) if why.status_code() == Some(StatusCode::NOT_FOUND) => {},
+                Err(why) => return Err(why),
+            }
+        }
+
+        Ok((roles, unresolved))
+    }
+
     /// Gets a list of the guild's members.
     ///
     /// Optionally pass in the `limit` to limit the number of results.
@@ -1191,6 +1260,17 @@ impl GuildId {
     ///
     /// **Note**: It will update instantly.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommandPermissionAmount`] if there are over 100
+    /// permissions.
+    ///
+    /// Returns a [`ModelError::MissingField`] if a permission is missing its
+    /// Id, type or whether it is allowed.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if
+    /// a permission is invalid.
+    ///
     /// [`ApplicationCommandPermission`]: crate::model::interactions::application_command::ApplicationCommandPermission
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
@@ -1207,6 +1287,7 @@ impl GuildId {
     {
         let mut map = CreateApplicationCommandPermissionsData::default();
         f(&mut map);
+        map.validate()?;
 
         http.as_ref()
             .edit_guild_application_command_permissions(
@@ -1218,6 +1299,17 @@ impl GuildId {
     }
 
     /// Overrides all application commands permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommandPermissionAmount`] if an application
+    /// command has over 100 permissions.
+    ///
+    /// Returns a [`ModelError::MissingField`] if a permission is missing its
+    /// Id, type or whether it is allowed.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if
+    /// a permission is invalid.
     #[cfg(feature = "unstable_discord_api")]
     #[cfg_attr(docsrs, doc(cfg(feature = "unstable_discord_api")))]
     pub async fn set_application_commands_permissions<F>(
@@ -1232,6 +1324,7 @@ impl GuildId {
     {
         let mut map = CreateApplicationCommandsPermissions::default();
         f(&mut map);
+        map.validate()?;
 
         http.as_ref()
             .edit_guild_application_commands_permissions(self.0, &Value::Array(map.0))
@@ -1,6 +1,8 @@
 #[cfg(feature = "model")]
 use std::borrow::Cow;
 use std::cmp::Reverse;
+#[cfg(feature = "model")]
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 #[cfg(feature = "model")]
@@ -61,6 +63,37 @@ pub struct Member {
     pub avatar: Option<String>,
 }
 
+/// Retrieves the Id and position of the highest of the given roles in the
+/// hierarchy, skipping roles missing from `guild_roles`.
+///
+/// If two or more roles have the same highest position, then the role with the
+/// lowest Id is the highest.
+#[cfg(feature = "model")]
+pub(crate) fn highest_role(
+    role_ids: &[RoleId],
+    guild_roles: &HashMap<RoleId, Role>,
+) -> Option<(RoleId, i64)> {
+    let mut highest = None;
+
+    for role_id in role_ids {
+        if let Some(role) = guild_roles.get(role_id) {
+            // Skip this role if this role in iteration has:
+            //
+            // - a position less than the recorded highest
+            // - a position equal to the recorded, but a higher ID
+            if let Some((id, pos)) = highest {
+                if role.position < pos || (role.position == pos && role.id > id) {
+                    continue;
+                }
+            }
+
+            highest = Some((role.id, role.position));
+        }
+    }
+
+    highest
+}
+
 #[cfg(feature = "model")]
 impl Member {
     /// Adds a [`Role`] to the member, editing its roles in-place if the request
@@ -249,27 +282,10 @@ impl Member {
     /// role with the lowest ID is the highest.
     #[cfg(feature = "cache")]
     pub async fn highest_role_info(&self, cache: impl AsRef<Cache>) -> Option<(RoleId, i64)> {
-        let guild_roles = cache.as_ref().guild_field(self.guild_id, |g| g.roles.clone()).await?;
-
-        let mut highest = None;
-
-        for role_id in &self.roles {
-            if let Some(role) = guild_roles.get(role_id) {
-                // Skip this role if this role in iteration has:
-                //
-                // - a position less than the recorded highest
-                // - a position equal to the recorded, but a higher ID
-                if let Some((id, pos)) = highest {
-                    if role.position < pos || (role.position == pos && role.id > id) {
-                        continue;
-                    }
-                }
-
-                highest = Some((role.id, role.position));
-            }
-        }
-
-        highest
+        cache
+            .as_ref()
+            .guild_field(self.guild_id, |guild| highest_role(&self.roles, &guild.roles))
+            .await?
     }
 
     /// Kick the member from the guild.
@@ -645,3 +661,28 @@ impl Serialize for ThreadMemberFlags {
         serializer.serialize_u64(self.bits())
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "model")]
+mod test {
+    use super::highest_role;
+    use crate::model::fixtures;
+    use crate::model::prelude::*;
+
+    fn gen_role(id: u64, position: i64) -> (RoleId, Role) {
+        let role = fixtures::role(id, position);
+
+        (role.id, role)
+    }
+
+    #[test]
+    fn test_highest_role() {
+        let guild_roles =
+            vec![gen_role(1, 2), gen_role(2, 5), gen_role(3, 5)].into_iter().collect();
+
+        assert_eq!(highest_role(&[RoleId(1), RoleId(2)], &guild_roles), Some((RoleId(2), 5)));
+        assert_eq!(highest_role(&[RoleId(3), RoleId(2)], &guild_roles), Some((RoleId(2), 5)));
+        assert_eq!(highest_role(&[RoleId(1), RoleId(4)], &guild_roles), Some((RoleId(1), 2)));
+        assert_eq!(highest_role(&[RoleId(4)], &guild_roles), None);
+    }
+}
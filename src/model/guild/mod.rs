@@ -1480,6 +1480,21 @@ impl Guild {
         self.members.values().find(|member| member.nick.as_ref().map_or(false, |nick| nick == name))
     }
 
+    /// Gets the roles of each of the given users who are cached members of
+    /// the guild.
+    ///
+    /// Users who are not in [`Self::members`] are left out of the returned
+    /// map. Use [`GuildId::members_roles`] to also fetch them over the REST
+    /// API.
+    pub fn members_roles(&self, user_ids: &[UserId]) -> HashMap<UserId, Vec<RoleId>> {
+        user_ids
+            .iter()
+            .filter_map(|user_id| {
+                self.members.get(user_id).map(|member| (*user_id, member.roles.clone()))
+            })
+            .collect()
+    }
+
     /// Retrieves all [`Member`] that start with a given [`String`].
     ///
     /// `sorted` decides whether the best early match of the `prefix`
@@ -3241,6 +3256,82 @@ mod test {
             assert_eq!(lhs, gen_member().display_name());
         }
 
+        #[test]
+        fn members_roles() {
+            let mut guild = gen();
+            if let Some(member) = guild.members.get_mut(&UserId(210)) {
+                member.roles = vec![RoleId(1), RoleId(2)];
+            }
+
+            let roles = guild.members_roles(&[UserId(210), UserId(211)]);
+
+            assert_eq!(roles.len(), 1);
+            assert_eq!(roles.get(&UserId(210)), Some(&vec![RoleId(1), RoleId(2)]));
+            assert!(!roles.contains_key(&UserId(211)));
+        }
+
+        #[cfg(feature = "cache")]
+        #[tokio::test]
+        #[allow(clippy::unwrap_used)]
+        async fn members_roles_fetches_uncached() {
+            use std::sync::Arc;
+
+            use crate::cache::Cache;
+            use crate::http::mock;
+            use crate::model::fixtures;
+
+            let cache = Arc::new(Cache::default());
+            let guild = fixtures::guild(vec![], vec![fixtures::member(7, &[3])]);
+            cache.guilds.write().await.insert(guild.id, guild);
+
+            // Only the uncached users are fetched, and those who aren't members
+            // are left out.
+            let member = serde_json::to_string(&fixtures::member(8, &[4])).unwrap();
+            let unknown = r#"{"code": 10007, "message": "Unknown Member"}"#.to_string();
+            let (proxy, server) = mock::serve(vec![(200, member), (404, unknown)]);
+            let http = mock::http(proxy).await;
+
+            let user_ids = [UserId(7), UserId(8), UserId(9)];
+            let (roles, unresolved) =
+                GuildId(1).members_roles((&cache, &http), &user_ids).await.unwrap();
+
+            assert_eq!(server.join().unwrap(), vec![
+                "GET /api/v9/guilds/1/members/8 HTTP/1.1",
+                "GET /api/v9/guilds/1/members/9 HTTP/1.1",
+            ]);
+            assert_eq!(roles.len(), 2);
+            assert_eq!(roles.get(&UserId(7)), Some(&vec![RoleId(3)]));
+            assert_eq!(roles.get(&UserId(8)), Some(&vec![RoleId(4)]));
+            assert!(unresolved.is_empty());
+
+            // Other errors are returned.
+            let (proxy, server) = mock::serve(vec![(400, "{}".to_string())]);
+            let http = mock::http(proxy).await;
+
+            assert!(GuildId(1).members_roles((&cache, &http), &user_ids).await.is_err());
+            assert_eq!(server.join().unwrap().len(), 1);
+
+            // Past 10 uncached users, the rest are left unresolved; cached
+            // users do not count towards the limit.
+            let (proxy, server) = mock::serve(
+                (100..110)
+                    .map(|id| (200, serde_json::to_string(&fixtures::member(id, &[5])).unwrap()))
+                    .collect(),
+            );
+            let http = mock::http(proxy).await;
+
+            let mut user_ids = vec![UserId(7)];
+            user_ids.extend((100..112).map(UserId));
+            let (roles, unresolved) =
+                GuildId(1).members_roles((&cache, &http), &user_ids).await.unwrap();
+
+            assert_eq!(server.join().unwrap().len(), 10);
+            assert_eq!(roles.len(), 11);
+            assert_eq!(roles.get(&UserId(7)), Some(&vec![RoleId(3)]));
+            assert_eq!(roles.get(&UserId(109)), Some(&vec![RoleId(5)]));
+            assert_eq!(unresolved, vec![UserId(110), UserId(111)]);
+        }
+
         #[tokio::test]
         #[allow(clippy::unwrap_used)]
         async fn member_named_nickname() {
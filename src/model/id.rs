@@ -6,6 +6,7 @@ use chrono::{DateTime, NaiveDateTime, Utc};
 use serde::de::{Deserialize, Deserializer};
 
 use super::utils::U64Visitor;
+use crate::constants::DISCORD_EPOCH;
 use crate::internal::prelude::*;
 
 macro_rules! id_u64 {
@@ -14,11 +15,11 @@ macro_rules! id_u64 {
             impl $name {
                 /// Retrieves the time that the Id was created at.
                 pub fn created_at(&self) -> DateTime<Utc> {
-                    let offset = self.0 >> 22;
-                    let secs = offset / 1000;
-                    let millis = (offset % 1000) * 1_000_000; // 1 million nanoseconds in a millisecond
+                    let timestamp = (self.0 >> 22) + DISCORD_EPOCH;
+                    let secs = timestamp / 1000;
+                    let nanos = (timestamp % 1000) * 1_000_000; // 1 million nanoseconds in a millisecond
 
-                    let tm = NaiveDateTime::from_timestamp(1_420_070_400 + secs as i64, millis as u32);
+                    let tm = NaiveDateTime::from_timestamp(secs as i64, nanos as u32);
                     DateTime::from_utc(tm, Utc)
                 }
 
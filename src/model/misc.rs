@@ -439,6 +439,9 @@ mod test {
                 discriminator: 4132,
                 name: "fake".to_string(),
                 public_flags: None,
+                banner: None,
+                accent_colour: None,
+                avatar_decoration: None,
             };
             let member = Member {
                 deaf: false,
@@ -27,6 +27,8 @@ pub mod channel;
 pub mod connection;
 pub mod error;
 pub mod event;
+#[cfg(all(test, feature = "builder"))]
+pub(crate) mod fixtures;
 pub mod gateway;
 pub mod guild;
 pub mod id;
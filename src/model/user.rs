@@ -1,8 +1,10 @@
 //! User information-related models.
 
+use std::convert::TryFrom;
 use std::fmt;
 #[cfg(feature = "model")]
 use std::fmt::Write;
+use std::str::FromStr;
 
 use bitflags::__impl_bitflags;
 use futures::future::{BoxFuture, FutureExt};
@@ -10,6 +12,8 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[cfg(feature = "model")]
 use serde_json::json;
 
+#[cfg(feature = "model")]
+use super::guild::highest_role;
 use super::prelude::*;
 use super::utils::deserialize_u16;
 #[cfg(feature = "model")]
@@ -80,7 +84,19 @@ impl CurrentUser {
     /// ```
     #[inline]
     pub fn avatar_url(&self) -> Option<String> {
-        avatar_url(self.id, self.avatar.as_ref())
+        avatar_url(self.id, self.avatar.as_ref(), 1024)
+    }
+
+    /// Returns the formatted URL of the user's icon in the given `size`, if
+    /// one exists.
+    ///
+    /// The `size` must be a power of two between 16 and 4096, inclusive;
+    /// otherwise [`None`] is returned.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the user has a GIF avatar.
+    #[inline]
+    pub fn avatar_url_with_size(&self, size: u16) -> Option<String> {
+        avatar_url(self.id, self.avatar.as_ref(), size)
     }
 
     /// Returns the formatted URL to the user's default avatar URL.
@@ -95,6 +111,8 @@ impl CurrentUser {
     ///
     /// This mutates the current user in-place.
     ///
+    /// Only the fields set on the builder are sent; the rest are left as-is.
+    ///
     /// Refer to [`EditProfile`]'s documentation for its methods.
     ///
     /// # Examples
@@ -129,20 +147,32 @@ impl CurrentUser {
     where
         F: FnOnce(&mut EditProfile) -> &mut EditProfile,
     {
-        let mut map = HashMap::new();
-        map.insert("username", Value::String(self.name.clone()));
+        let map = self.edit_map(f);
 
-        if let Some(email) = self.email.as_ref() {
-            map.insert("email", Value::String(email.clone()));
-        }
+        *self = http.as_ref().edit_profile(&map).await?;
+
+        Ok(())
+    }
 
-        let mut edit_profile = EditProfile(map);
+    /// Builds the map sent by [`Self::edit`].
+    ///
+    /// Only fields set by `f` are sent, so that unrelated fields - such as the
+    /// username - are not needlessly re-submitted. The exception is the email
+    /// address, which Discord requires alongside the current password.
+    fn edit_map<F>(&self, f: F) -> JsonMap
+    where
+        F: FnOnce(&mut EditProfile) -> &mut EditProfile,
+    {
+        let mut edit_profile = EditProfile::default();
         f(&mut edit_profile);
-        let map = utils::hashmap_to_json_map(edit_profile.0);
 
-        *self = http.as_ref().edit_profile(&map).await?;
+        if edit_profile.0.contains_key("password") && !edit_profile.0.contains_key("email") {
+            if let Some(email) = self.email.as_ref() {
+                edit_profile.0.insert("email", Value::String(email.clone()));
+            }
+        }
 
-        Ok(())
+        utils::hashmap_to_json_map(edit_profile.0)
     }
 
     /// Retrieves the URL to the current user's avatar, falling back to the
@@ -417,6 +447,17 @@ pub enum DefaultAvatar {
 }
 
 impl DefaultAvatar {
+    /// Returns the default avatar for a user with the given discriminator.
+    pub fn from_discriminator(discriminator: u16) -> Self {
+        match discriminator % 5 {
+            0 => DefaultAvatar::Blurple,
+            1 => DefaultAvatar::Grey,
+            2 => DefaultAvatar::Green,
+            3 => DefaultAvatar::Orange,
+            _ => DefaultAvatar::Red,
+        }
+    }
+
     /// Retrieves the String hash of the default avatar.
     ///
     /// # Errors
@@ -438,7 +479,7 @@ impl DefaultAvatar {
 ///
 /// [`DoNotDisturb`]: OnlineStatus::DoNotDisturb
 /// [`Invisible`]: OnlineStatus::Invisible
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 #[non_exhaustive]
 pub enum OnlineStatus {
     #[serde(rename = "dnd")]
@@ -465,12 +506,64 @@ impl OnlineStatus {
     }
 }
 
+impl<'de> Deserialize<'de> for OnlineStatus {
+    /// Deserializes a status, falling back to [`OnlineStatus::Offline`] for a
+    /// status unknown to the library, so that a status newly introduced by
+    /// Discord does not fail deserializing the whole event.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let status = String::deserialize(deserializer)?;
+
+        Ok(status.parse().unwrap_or(OnlineStatus::Offline))
+    }
+}
+
 impl Default for OnlineStatus {
     fn default() -> OnlineStatus {
         OnlineStatus::Online
     }
 }
 
+/// The error returned when a string could not be parsed into an
+/// [`OnlineStatus`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OnlineStatusParseError;
+
+impl fmt::Display for OnlineStatusParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid online status")
+    }
+}
+
+impl std::error::Error for OnlineStatusParseError {}
+
+impl FromStr for OnlineStatus {
+    type Err = OnlineStatusParseError;
+
+    /// Parses an [`OnlineStatus`] from the name Discord uses for it, as
+    /// returned by [`OnlineStatus::name`]. Matching is case-insensitive.
+    fn from_str(s: &str) -> StdResult<Self, Self::Err> {
+        [
+            OnlineStatus::DoNotDisturb,
+            OnlineStatus::Idle,
+            OnlineStatus::Invisible,
+            OnlineStatus::Offline,
+            OnlineStatus::Online,
+        ]
+        .iter()
+        .copied()
+        .find(|status| status.name().eq_ignore_ascii_case(s))
+        .ok_or(OnlineStatusParseError)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for OnlineStatus {
+    type Error = OnlineStatusParseError;
+
+    fn try_from(s: &'a str) -> StdResult<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 /// Information about a user.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
@@ -493,6 +586,29 @@ pub struct User {
     pub name: String,
     /// the public flags on a user's account
     pub public_flags: Option<UserPublicFlags>,
+    /// Optional banner hash.
+    ///
+    /// **Note**: This will only be present if the user was fetched via the
+    /// REST API, e.g. with [`UserId::to_user`].
+    #[serde(default)]
+    pub banner: Option<String>,
+    /// The user's banner colour, used when no banner image is set.
+    ///
+    /// **Note**: This will only be present if the user was fetched via the
+    /// REST API, e.g. with [`UserId::to_user`].
+    #[cfg(feature = "utils")]
+    #[serde(rename = "accent_color", default)]
+    pub accent_colour: Option<Colour>,
+    /// The user's banner colour, used when no banner image is set.
+    ///
+    /// **Note**: This will only be present if the user was fetched via the
+    /// REST API, e.g. with [`UserId::to_user`].
+    #[cfg(not(feature = "utils"))]
+    #[serde(rename = "accent_color", default)]
+    pub accent_colour: Option<u32>,
+    /// Optional avatar decoration hash.
+    #[serde(default)]
+    pub avatar_decoration: Option<String>,
 }
 
 /// User's public flags
@@ -560,6 +676,9 @@ impl Default for User {
     /// - **discriminator** to `1432`.
     /// - **name** to `"test"`.
     /// - **public_flags** to [`None`].
+    /// - **banner** to [`None`].
+    /// - **accent_colour** to [`None`].
+    /// - **avatar_decoration** to [`None`].
     fn default() -> Self {
         User {
             id: UserId(210),
@@ -568,13 +687,15 @@ impl Default for User {
             discriminator: 1432,
             name: "test".to_string(),
             public_flags: None,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
         }
     }
 }
 
 use std::hash::{Hash, Hasher};
 
-#[cfg(feature = "model")]
 use chrono::{DateTime, Utc};
 
 impl PartialEq for User {
@@ -591,6 +712,28 @@ impl Hash for User {
     }
 }
 
+impl PartialEq for CurrentUser {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for CurrentUser {}
+
+impl Hash for CurrentUser {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.id.hash(hasher);
+    }
+}
+
+impl User {
+    /// Retrieves the time that this user was created at.
+    #[inline]
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.id.created_at()
+    }
+}
+
 #[cfg(feature = "model")]
 impl User {
     /// Returns the formatted URL of the user's icon, if one exists.
@@ -598,7 +741,37 @@ impl User {
     /// This will produce a WEBP image URL, or GIF if the user has a GIF avatar.
     #[inline]
     pub fn avatar_url(&self) -> Option<String> {
-        avatar_url(self.id, self.avatar.as_ref())
+        avatar_url(self.id, self.avatar.as_ref(), 1024)
+    }
+
+    /// Returns the formatted URL of the user's icon in the given `size`, if
+    /// one exists.
+    ///
+    /// The `size` must be a power of two between 16 and 4096, inclusive;
+    /// otherwise [`None`] is returned.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the user has a GIF avatar.
+    #[inline]
+    pub fn avatar_url_with_size(&self, size: u16) -> Option<String> {
+        avatar_url(self.id, self.avatar.as_ref(), size)
+    }
+
+    /// Returns the formatted URL of the user's banner, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the user has a GIF banner.
+    ///
+    /// **Note**: The banner is only known if the user was fetched via the
+    /// REST API, e.g. with [`UserId::to_user`].
+    #[inline]
+    pub fn banner_url(&self) -> Option<String> {
+        banner_url(self.id, self.banner.as_ref())
+    }
+
+    /// Returns the formatted URL of the user's avatar decoration, if one
+    /// exists.
+    #[inline]
+    pub fn avatar_decoration_url(&self) -> Option<String> {
+        avatar_decoration_url(self.id, self.avatar_decoration.as_ref())
     }
 
     /// Creates a direct message channel between the [current user] and the
@@ -620,12 +793,6 @@ impl User {
         self.id.create_dm_channel(cache_http).await
     }
 
-    /// Retrieves the time that this user was created at.
-    #[inline]
-    pub fn created_at(&self) -> DateTime<Utc> {
-        self.id.created_at()
-    }
-
     /// Returns the formatted URL to the user's default avatar URL.
     ///
     /// This will produce a PNG URL.
@@ -713,7 +880,51 @@ impl User {
     where
         for<'a, 'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
     {
-        self.create_dm_channel(&cache_http).await?.send_message(&cache_http.http(), f).await
+        self._direct_message(cache_http, false, f).await
+    }
+
+    /// Sends a message to a user through a direct message channel, even if the
+    /// user is a bot.
+    ///
+    /// This is the same as [`Self::direct_message`], but skips the check that
+    /// prevents messaging bot users. Discord may still refuse to open a direct
+    /// message channel with another bot.
+    ///
+    /// # Errors
+    ///
+    /// May return an [`Error::Http`] if the message was illformed, or if the
+    /// user cannot be sent a direct message.
+    ///
+    /// [`Error::Json`] can also be returned if there is an error deserializing
+    /// the API response.
+    ///
+    /// [`Error::Http`]: crate::error::Error::Http
+    /// [`Error::Json`]: crate::error::Error::Json
+    pub async fn direct_message_allow_bot<F>(
+        &self,
+        cache_http: impl CacheHttp,
+        f: F,
+    ) -> Result<Message>
+    where
+        for<'a, 'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
+    {
+        self._direct_message(cache_http, true, f).await
+    }
+
+    async fn _direct_message<F>(
+        &self,
+        cache_http: impl CacheHttp,
+        allow_bot: bool,
+        f: F,
+    ) -> Result<Message>
+    where
+        for<'a, 'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
+    {
+        if self.bot && !allow_bot {
+            return Err(Error::Model(ModelError::MessagingBot));
+        }
+
+        self.id.create_dm_channel(&cache_http).await?.send_message(&cache_http.http(), f).await
     }
 
     /// This is an alias of [`Self::direct_message`].
@@ -814,6 +1025,63 @@ impl User {
         .boxed()
     }
 
+    /// Retrieves the Id of the user's highest role in the hierarchy of a
+    /// guild, if they have one.
+    ///
+    /// The member and the guild's roles are looked up in the [`Cache`] first,
+    /// falling back to the REST API. Roles of the member missing from the
+    /// guild are skipped.
+    ///
+    /// Returns [`None`] if the user is not in the guild, has no roles, or if a
+    /// request fails.
+    ///
+    /// Three forms of data may be passed in to the guild parameter: either a
+    /// [`PartialGuild`], a [`GuildId`], or a `u64`.
+    ///
+    /// [`Cache`]: crate::cache::Cache
+    pub async fn top_role_in(
+        &self,
+        cache_http: impl CacheHttp,
+        guild: impl Into<GuildContainer>,
+    ) -> Option<RoleId> {
+        let (guild_id, guild_roles) = match guild.into() {
+            GuildContainer::Guild(partial_guild) => (partial_guild.id, Some(partial_guild.roles)),
+            GuildContainer::Id(guild_id) => (guild_id, None),
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                let top_role = cache
+                    .guild_field(guild_id, |guild| {
+                        guild.members.get(&self.id).map(|member| {
+                            highest_role(
+                                &member.roles,
+                                guild_roles.as_ref().unwrap_or(&guild.roles),
+                            )
+                        })
+                    })
+                    .await;
+
+                if let Some(Some(top_role)) = top_role {
+                    return top_role.map(|(role_id, _)| role_id);
+                }
+            }
+        }
+
+        let member = cache_http.http().get_member(guild_id.0, self.id.0).await.ok()?;
+        let guild_roles = match guild_roles {
+            Some(guild_roles) => guild_roles,
+            None => {
+                let roles = cache_http.http().get_guild_roles(guild_id.0).await.ok()?;
+
+                roles.into_iter().map(|role| (role.id, role)).collect()
+            },
+        };
+
+        highest_role(&member.roles, &guild_roles).map(|(role_id, _)| role_id)
+    }
+
     /// Refreshes the information about the user.
     ///
     /// Replaces the instance with the data retrieved over the REST API.
@@ -906,6 +1174,20 @@ impl User {
         guild_id.member(cache_http, &self.id).await.ok().and_then(|member| member.nick)
     }
 
+    /// Returns the name to display for the user in the given `guild_id`.
+    ///
+    /// This is the user's nickname in the guild if they have one, otherwise
+    /// their username. Refer to [`Self::nick_in`] for how the nickname is
+    /// retrieved.
+    #[inline]
+    pub async fn display_name_in(
+        &self,
+        cache_http: impl CacheHttp,
+        guild_id: impl Into<GuildId>,
+    ) -> String {
+        self.nick_in(cache_http, guild_id).await.unwrap_or_else(|| self.name.clone())
+    }
+
     /// Returns a future that will await one message by this user.
     #[cfg(feature = "collector")]
     #[cfg_attr(docsrs, doc(cfg(feature = "collector")))]
@@ -1038,6 +1320,9 @@ impl From<CurrentUser> for User {
             id: user.id,
             name: user.name,
             public_flags: user.public_flags,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
         }
     }
 }
@@ -1051,6 +1336,30 @@ impl<'a> From<&'a CurrentUser> for User {
             id: user.id,
             name: user.name.clone(),
             public_flags: user.public_flags,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
+        }
+    }
+}
+
+/// Converts a [`User`] into a [`CurrentUser`].
+///
+/// **Note**: A [`User`] does not carry the account details only known about
+/// the current user. `email` and `verified` are set to [`None`] and
+/// `mfa_enabled` to `false`; these do not reflect the actual account.
+impl From<User> for CurrentUser {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            avatar: user.avatar,
+            bot: user.bot,
+            discriminator: user.discriminator,
+            email: None,
+            mfa_enabled: false,
+            name: user.name,
+            verified: None,
+            public_flags: user.public_flags,
         }
     }
 }
@@ -1098,14 +1407,39 @@ impl<'a> From<&'a User> for UserId {
 }
 
 #[cfg(feature = "model")]
-fn avatar_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
+fn avatar_url(user_id: UserId, hash: Option<&String>, size: u16) -> Option<String> {
+    if !is_valid_image_size(size) {
+        return None;
+    }
+
     hash.map(|hash| {
         let ext = if hash.starts_with("a_") { "gif" } else { "webp" };
 
-        cdn!("/avatars/{}/{}.{}?size=1024", user_id.0, hash, ext)
+        cdn!("/avatars/{}/{}.{}?size={}", user_id.0, hash, ext, size)
     })
 }
 
+#[cfg(feature = "model")]
+fn banner_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
+    hash.map(|hash| {
+        let ext = if hash.starts_with("a_") { "gif" } else { "webp" };
+
+        cdn!("/banners/{}/{}.{}?size=1024", user_id.0, hash, ext)
+    })
+}
+
+#[cfg(feature = "model")]
+fn avatar_decoration_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
+    hash.map(|hash| cdn!("/avatar-decorations/{}/{}.png", user_id.0, hash))
+}
+
+/// Whether the CDN can serve an image in the given `size`: a power of two
+/// between 16 and 4096.
+#[cfg(feature = "model")]
+fn is_valid_image_size(size: u16) -> bool {
+    size.is_power_of_two() && (16..=4096).contains(&size)
+}
+
 #[cfg(feature = "model")]
 fn default_avatar_url(discriminator: u16) -> String {
     cdn!("/embed/avatars/{}.png", discriminator % 5u16)
@@ -1134,9 +1468,84 @@ fn tag(name: &str, discriminator: u16) -> String {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod test {
+    #[test]
+    fn test_created_at() {
+        use crate::model::id::UserId;
+        use crate::model::user::User;
+
+        let mut user = User::default();
+        user.id = UserId(175928847299117063);
+
+        assert_eq!(user.created_at().to_rfc3339(), "2016-04-30T11:18:25.796+00:00");
+        assert_eq!(user.created_at(), user.id.created_at());
+    }
+
+    #[test]
+    fn test_mention() {
+        use crate::model::misc::Mentionable;
+        use crate::model::user::User;
+
+        let user = User::default();
+
+        assert_eq!(user.mention().to_string(), format!("{}", user));
+        assert_eq!(user.mention().to_string(), "<@210>");
+    }
+
+    #[test]
+    fn current_user_identity() {
+        use std::collections::HashSet;
+
+        use crate::model::id::UserId;
+        use crate::model::user::CurrentUser;
+
+        let first = CurrentUser {
+            id: UserId(210),
+            avatar: Some("abc".to_string()),
+            ..Default::default()
+        };
+        let second = CurrentUser {
+            id: UserId(210),
+            avatar: Some("def".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(first, second);
+
+        let mut set = HashSet::new();
+        set.insert(first);
+        set.insert(second);
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn current_user_from_user() {
+        use crate::model::user::{CurrentUser, User, UserPublicFlags};
+
+        let user = User {
+            public_flags: Some(UserPublicFlags::VERIFIED_BOT),
+            ..Default::default()
+        };
+        let current_user = CurrentUser::from(user.clone());
+
+        assert_eq!(current_user.id, user.id);
+        assert_eq!(current_user.avatar, user.avatar);
+        assert_eq!(current_user.bot, user.bot);
+        assert_eq!(current_user.discriminator, user.discriminator);
+        assert_eq!(current_user.name, user.name);
+        assert_eq!(current_user.public_flags, user.public_flags);
+        assert!(current_user.email.is_none());
+        assert!(current_user.verified.is_none());
+        assert!(!current_user.mfa_enabled);
+    }
+
     #[cfg(feature = "model")]
     mod model {
-        use crate::model::user::User;
+        use serde_json::json;
+
+        use crate::http::mock;
+        use crate::model::id::UserId;
+        use crate::model::user::{CurrentUser, User};
+        use crate::model::ModelError;
+        use crate::Error;
 
         #[test]
         fn test_core() {
@@ -1154,6 +1563,7 @@ mod test {
 
             user.avatar = None;
             assert!(user.avatar_url().is_none());
+            assert!(user.avatar_url_with_size(64).is_none());
 
             assert_eq!(user.tag(), "test#1432");
         }
@@ -1175,5 +1585,280 @@ mod test {
             user.discriminator = 4;
             assert!(user.default_avatar_url().ends_with("4.png"));
         }
+
+        #[test]
+        fn avatar_url_sizes() {
+            let mut user = User::default();
+
+            assert!(user
+                .avatar_url_with_size(64)
+                .unwrap()
+                .ends_with("/avatars/210/abc.webp?size=64"));
+            assert!(user.avatar_url_with_size(16).is_some());
+            assert!(user.avatar_url_with_size(4096).is_some());
+            assert!(user.avatar_url_with_size(100).is_none());
+            assert!(user.avatar_url_with_size(8).is_none());
+            assert!(user.avatar_url_with_size(8192).is_none());
+
+            user.avatar = Some("a_aaa".to_string());
+            assert!(user
+                .avatar_url_with_size(128)
+                .unwrap()
+                .ends_with("/avatars/210/a_aaa.gif?size=128"));
+
+            let current_user = CurrentUser {
+                id: UserId(210),
+                avatar: Some("abc".to_string()),
+                ..Default::default()
+            };
+            assert!(current_user
+                .avatar_url_with_size(32)
+                .unwrap()
+                .ends_with("/avatars/210/abc.webp?size=32"));
+            assert!(current_user.avatar_url_with_size(33).is_none());
+        }
+
+        #[test]
+        fn banner_url() {
+            let mut user = User::default();
+            assert!(user.banner_url().is_none());
+
+            user.banner = Some("abc".to_string());
+            assert!(user.banner_url().unwrap().ends_with("/banners/210/abc.webp?size=1024"));
+
+            user.banner = Some("a_aaa".to_string());
+            assert!(user.banner_url().unwrap().ends_with("/banners/210/a_aaa.gif?size=1024"));
+        }
+
+        #[test]
+        fn deserialize_without_banner() {
+            let user: User = serde_json::from_str(
+                r#"{"id":"210","avatar":null,"discriminator":"1432","username":"test"}"#,
+            )
+            .unwrap();
+
+            assert!(user.banner.is_none());
+            assert!(user.accent_colour.is_none());
+        }
+
+        #[test]
+        fn avatar_decoration_url() {
+            let mut user = User::default();
+            assert!(user.avatar_decoration_url().is_none());
+
+            user.avatar_decoration = Some("abc".to_string());
+            assert!(user
+                .avatar_decoration_url()
+                .unwrap()
+                .ends_with("/avatar-decorations/210/abc.png"));
+        }
+
+        #[test]
+        fn deserialize_without_avatar_decoration() {
+            let user: User = serde_json::from_str(
+                r#"{"id":"210","avatar":null,"discriminator":"1432","username":"test"}"#,
+            )
+            .unwrap();
+
+            assert!(user.avatar_decoration.is_none());
+        }
+
+        #[tokio::test]
+        async fn direct_message_bot_guard() {
+            let recipient = json!({
+                "id": "5",
+                "avatar": null,
+                "bot": true,
+                "discriminator": "0001",
+                "username": "user 5",
+            });
+            let channel = json!({
+                "id": "2",
+                "last_message_id": null,
+                "recipients": [recipient],
+                "type": 1,
+            });
+            let message = json!({
+                "id": "3",
+                "attachments": [],
+                "author": recipient,
+                "channel_id": "2",
+                "content": "hi",
+                "edited_timestamp": null,
+                "embeds": [],
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2021-01-01T00:00:00+00:00",
+                "tts": false,
+                "type": 0,
+            });
+
+            let (proxy, server) =
+                mock::serve(vec![(200, channel.to_string()), (200, message.to_string())]);
+            let http = mock::http(proxy).await;
+            let user: User = serde_json::from_value(recipient).unwrap();
+
+            let result = user.direct_message(&http, |m| m.content("hi")).await;
+            assert!(matches!(result, Err(Error::Model(ModelError::MessagingBot))));
+
+            let message = user.direct_message_allow_bot(&http, |m| m.content("hi")).await.unwrap();
+            assert_eq!(message.content, "hi");
+
+            // Only the allowed message opened a channel.
+            assert_eq!(server.join().unwrap(), [
+                "POST /api/v9/users/@me/channels HTTP/1.1",
+                "POST /api/v9/channels/2/messages HTTP/1.1",
+            ]);
+        }
+
+        #[cfg(feature = "cache")]
+        #[tokio::test]
+        async fn display_name_in() {
+            use std::sync::Arc;
+
+            use crate::cache::Cache;
+            use crate::http::Http;
+            use crate::model::fixtures;
+            use crate::model::id::GuildId;
+
+            let user = User::default();
+            let with_nick = GuildId(1);
+            let without_nick = GuildId(2);
+
+            let cache = Arc::new(Cache::default());
+            let http = Http::new_with_token("");
+
+            for (guild_id, nick) in vec![(with_nick, Some("nick")), (without_nick, None)] {
+                let mut member = fixtures::member(user.id.0, &[]);
+                member.guild_id = guild_id;
+                member.nick = nick.map(ToString::to_string);
+                member.user = user.clone();
+
+                let mut guild = fixtures::guild(vec![], vec![member]);
+                guild.id = guild_id;
+
+                cache.guilds.write().await.insert(guild_id, guild);
+            }
+
+            assert_eq!(user.display_name_in((&cache, &http), with_nick).await, "nick");
+            assert_eq!(user.display_name_in((&cache, &http), without_nick).await, "test");
+        }
+
+        #[tokio::test]
+        async fn top_role_in() {
+            use crate::model::fixtures;
+            use crate::model::id::{GuildId, RoleId};
+
+            let user = User::default();
+
+            let member = serde_json::to_string(&fixtures::member(user.id.0, &[1, 2])).unwrap();
+            let role = |id: u64, position: i64| {
+                json!({
+                    "id": id.to_string(),
+                    "color": 0,
+                    "hoist": false,
+                    "managed": false,
+                    "name": format!("role {}", id),
+                    "permissions": "0",
+                    "position": position,
+                })
+            };
+            let roles = json!([role(1, 2), role(2, 5)]);
+            let (proxy, server) = mock::serve(vec![(200, member), (200, roles.to_string())]);
+            let http = mock::http(proxy).await;
+
+            assert_eq!(user.top_role_in(&http, GuildId(1)).await, Some(RoleId(2)));
+            assert_eq!(server.join().unwrap(), [
+                "GET /api/v9/guilds/1/members/210 HTTP/1.1",
+                "GET /api/v9/guilds/1/roles HTTP/1.1",
+            ]);
+        }
+
+        #[cfg(feature = "cache")]
+        #[tokio::test]
+        async fn top_role_in_cached() {
+            use std::sync::Arc;
+
+            use crate::cache::Cache;
+            use crate::http::Http;
+            use crate::model::fixtures;
+            use crate::model::id::{GuildId, RoleId};
+
+            let user = User::default();
+
+            let cache = Arc::new(Cache::default());
+            let http = Http::new_with_token("");
+
+            let roles = vec![fixtures::role(1, 5), fixtures::role(2, 2)];
+            let guild = fixtures::guild(roles, vec![fixtures::member(user.id.0, &[2, 1])]);
+            cache.guilds.write().await.insert(guild.id, guild);
+
+            assert_eq!(user.top_role_in((&cache, &http), GuildId(1)).await, Some(RoleId(1)));
+        }
+
+        #[test]
+        fn edit_sends_only_touched_fields() {
+            let user = CurrentUser {
+                email: Some("test@example.com".to_string()),
+                name: "test".to_string(),
+                ..Default::default()
+            };
+
+            let map = user.edit_map(|p| p.avatar(None));
+            assert_eq!(map.len(), 1);
+            assert!(map.get("avatar").unwrap().is_null());
+            assert!(!map.contains_key("username"));
+            assert!(!map.contains_key("email"));
+
+            let map = user.edit_map(|p| p.password("hunter2").new_password("hunter3"));
+            assert_eq!(map.get("email").unwrap(), "test@example.com");
+            assert!(!map.contains_key("username"));
+        }
+
+        #[test]
+        fn online_status_from_str() {
+            use std::convert::TryFrom;
+
+            use crate::model::user::{OnlineStatus, OnlineStatusParseError};
+
+            assert_eq!("dnd".parse(), Ok(OnlineStatus::DoNotDisturb));
+            assert_eq!("idle".parse(), Ok(OnlineStatus::Idle));
+            assert_eq!("invisible".parse(), Ok(OnlineStatus::Invisible));
+            assert_eq!("offline".parse(), Ok(OnlineStatus::Offline));
+            assert_eq!("online".parse(), Ok(OnlineStatus::Online));
+            assert_eq!("DND".parse(), Ok(OnlineStatus::DoNotDisturb));
+            assert_eq!(OnlineStatus::try_from("idle"), Ok(OnlineStatus::Idle));
+
+            assert_eq!("away".parse::<OnlineStatus>(), Err(OnlineStatusParseError));
+            assert_eq!(OnlineStatus::try_from(""), Err(OnlineStatusParseError));
+        }
+
+        #[test]
+        fn online_status_deserialize_unknown() {
+            use crate::model::user::OnlineStatus;
+
+            assert_eq!(
+                serde_json::from_str::<OnlineStatus>(r#""idle""#).unwrap(),
+                OnlineStatus::Idle
+            );
+            assert_eq!(
+                serde_json::from_str::<OnlineStatus>(r#""streaming""#).unwrap(),
+                OnlineStatus::Offline
+            );
+        }
+
+        #[test]
+        fn default_avatar_from_discriminator() {
+            use crate::model::user::DefaultAvatar;
+
+            assert_eq!(DefaultAvatar::from_discriminator(0), DefaultAvatar::Blurple);
+            assert_eq!(DefaultAvatar::from_discriminator(1), DefaultAvatar::Grey);
+            assert_eq!(DefaultAvatar::from_discriminator(2), DefaultAvatar::Green);
+            assert_eq!(DefaultAvatar::from_discriminator(3), DefaultAvatar::Orange);
+            assert_eq!(DefaultAvatar::from_discriminator(4), DefaultAvatar::Red);
+            assert_eq!(DefaultAvatar::from_discriminator(1432), DefaultAvatar::Green);
+        }
     }
 }
@@ -247,6 +247,9 @@ fn dummy_message() -> Message {
             discriminator: 0x0000,
             name: String::new(),
             public_flags: None,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
         },
         channel_id: ChannelId::default(),
         content: String::new(),
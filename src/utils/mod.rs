@@ -242,6 +242,36 @@ pub fn parse_mention(mention: impl AsRef<str>) -> Option<u64> {
     }
 }
 
+/// Splits a user tag, such as `"zey#5479"`, into the user's name and
+/// discriminator.
+///
+/// The tag is split on its last `#`, so names containing a hash are
+/// supported. If there is no `#`, or the discriminator does not consist of
+/// exactly four digits, then [`None`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::parse_tag;
+///
+/// assert_eq!(parse_tag("zey#5479"), Some(("zey".to_string(), 5479)));
+/// assert_eq!(parse_tag("zey#0001"), Some(("zey".to_string(), 1)));
+///
+/// assert!(parse_tag("zey").is_none());
+/// assert!(parse_tag("zey#547").is_none());
+/// ```
+pub fn parse_tag(tag: impl AsRef<str>) -> Option<(String, u16)> {
+    let tag = tag.as_ref();
+    let pos = tag.rfind('#')?;
+    let (name, discriminator) = (&tag[..pos], &tag[pos + 1..]);
+
+    if discriminator.len() != 4 || !discriminator.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some((name.to_string(), discriminator.parse().ok()?))
+}
+
 /// Retrieves the animated state, name and Id from an emoji mention, in the form of an
 /// [`EmojiIdentifier`].
 ///
@@ -286,11 +316,11 @@ pub fn parse_emoji(mention: impl AsRef<str>) -> Option<EmojiIdentifier> {
     if (mention.starts_with("<:") || mention.starts_with("<a:")) && mention.ends_with('>') {
         let mut name = String::default();
         let mut id = String::default();
-        let animated = &mention[1..3] == "a:";
+        let animated = mention.starts_with("<a:");
 
         let start = if animated { 3 } else { 2 };
 
-        for (i, x) in mention[start..].chars().enumerate() {
+        for (i, x) in mention[start..].char_indices() {
             if x == ':' {
                 let from = i + start + 1;
 
@@ -789,11 +819,25 @@ mod test {
         assert_eq!(parse_channel("<#12345>").unwrap(), 12_345);
     }
 
+    #[test]
+    fn test_tag_parser() {
+        assert_eq!(parse_tag("zey#5479"), Some(("zey".to_string(), 5479)));
+        assert_eq!(parse_tag("a#b#0042"), Some(("a#b".to_string(), 42)));
+        assert_eq!(parse_tag("zey#547"), None);
+        assert_eq!(parse_tag("zey#+547"), None);
+        assert_eq!(parse_tag("zey"), None);
+    }
+
     #[test]
     fn test_emoji_parser() {
         let emoji = parse_emoji("<:name:12345>").unwrap();
         assert_eq!(emoji.name, "name");
         assert_eq!(emoji.id, 12_345);
+
+        let emoji = parse_emoji("<:\u{e9}t\u{e9}:12345>").unwrap();
+        assert_eq!(emoji.name, "\u{e9}t\u{e9}");
+        assert_eq!(emoji.id, 12_345);
+        assert!(parse_emoji("<:\u{e9}\u{e9}\u{e9}>").is_none());
     }
 
     #[test]
@@ -818,6 +862,9 @@ mod test {
             discriminator: 0000,
             name: "Crab".to_string(),
             public_flags: None,
+            banner: None,
+            accent_colour: None,
+            avatar_decoration: None,
         };
 
         #[allow(deprecated)]
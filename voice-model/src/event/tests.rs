@@ -120,6 +120,19 @@ fn deserialize_heartbeat_json() {
     });
 }
 
+#[test]
+fn heartbeat_round_trip() {
+    let hb = Heartbeat::new(1501184119561);
+
+    let json = serde_json::to_string(&hb).unwrap();
+    assert_eq!(json, r#""1501184119561""#);
+    assert_eq!(serde_json::from_str::<Heartbeat>(&json).unwrap(), hb);
+    assert_eq!(
+        serde_json::from_str::<Heartbeat>("1501184119561").unwrap(),
+        hb
+    );
+}
+
 #[test]
 fn deserialize_session_description_json() {
     let json_data = r#"{
@@ -484,6 +497,19 @@ fn serialize_session_description() {
     );
 }
 
+#[test]
+fn serialize_speaking_state() {
+    let microphone = SpeakingState::MICROPHONE;
+    let priority = SpeakingState::MICROPHONE | SpeakingState::PRIORITY;
+
+    assert_eq!(serde_json::to_string(&microphone).unwrap(), "1");
+    assert_eq!(serde_json::to_string(&priority).unwrap(), "5");
+    assert_eq!(
+        serde_json::to_string(&SpeakingState::SOUNDSHARE).unwrap(),
+        "2"
+    );
+}
+
 #[test]
 fn serialize_speaking() {
     let value: Event = Speaking {
@@ -0,0 +1,186 @@
+use crate::payload::{Heartbeat, HeartbeatAck};
+use std::{
+    collections::VecDeque,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    time::{Duration, Instant},
+};
+
+/// The number of unacknowledged heartbeats kept by a [`HeartbeatTracker`].
+///
+/// Once exceeded, the oldest heartbeat is forgotten.
+const MAX_PENDING: usize = 16;
+
+/// Correlates sent [`Heartbeat`]s with received [`HeartbeatAck`]s to measure
+/// the round-trip latency of a voice websocket connection.
+#[derive(Clone, Debug)]
+pub struct HeartbeatTracker {
+    pending: VecDeque<(u64, Instant)>,
+    timeout: Duration,
+}
+
+impl HeartbeatTracker {
+    /// Creates a tracker considering any heartbeat acknowledged later than
+    /// `timeout` after being sent as expired.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            pending: VecDeque::new(),
+            timeout,
+        }
+    }
+
+    /// Records that a heartbeat was just sent.
+    pub fn sent(&mut self, heartbeat: Heartbeat) {
+        self.sent_at(heartbeat, Instant::now());
+    }
+
+    /// Validates a received acknowledgement against the sent heartbeats,
+    /// returning the round-trip latency of the matching heartbeat.
+    ///
+    /// Heartbeats sent before the acknowledged one are discarded, as their
+    /// acknowledgements are not expected to arrive anymore.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HeartbeatAckError::Mismatch`] if no pending heartbeat carried
+    /// the acknowledged nonce, and [`HeartbeatAckError::Expired`] if the
+    /// acknowledgement arrived after the tracker's timeout.
+    pub fn acknowledge(&mut self, ack: HeartbeatAck) -> Result<Duration, HeartbeatAckError> {
+        self.acknowledge_at(ack, Instant::now())
+    }
+
+    /// The number of sent heartbeats which were not acknowledged yet.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    fn sent_at(&mut self, heartbeat: Heartbeat, at: Instant) {
+        if self.pending.len() == MAX_PENDING {
+            self.pending.pop_front();
+        }
+
+        self.pending.push_back((heartbeat.nonce, at));
+    }
+
+    fn acknowledge_at(
+        &mut self,
+        ack: HeartbeatAck,
+        at: Instant,
+    ) -> Result<Duration, HeartbeatAckError> {
+        let index = self
+            .pending
+            .iter()
+            .position(|(nonce, _)| *nonce == ack.nonce)
+            .ok_or(HeartbeatAckError::Mismatch(ack.nonce))?;
+
+        let (_, sent) = self.pending[index];
+        self.pending.drain(..=index);
+
+        let latency = at.saturating_duration_since(sent);
+
+        if latency > self.timeout {
+            Err(HeartbeatAckError::Expired(ack.nonce, latency))
+        } else {
+            Ok(latency)
+        }
+    }
+}
+
+/// An error returned when a [`HeartbeatAck`] could not be correlated by a
+/// [`HeartbeatTracker`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum HeartbeatAckError {
+    /// The acknowledged heartbeat was sent, but its acknowledgement arrived
+    /// after the given latency, exceeding the tracker's timeout.
+    Expired(u64, Duration),
+    /// No pending heartbeat carried the acknowledged nonce.
+    Mismatch(u64),
+}
+
+impl Display for HeartbeatAckError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            HeartbeatAckError::Expired(nonce, latency) => {
+                write!(f, "Heartbeat {} acknowledged after {:?}", nonce, latency)
+            },
+            HeartbeatAckError::Mismatch(nonce) => write!(f, "Heartbeat nonce mismatch: {}", nonce),
+        }
+    }
+}
+
+impl Error for HeartbeatAckError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matched_ack_returns_latency() {
+        let mut tracker = HeartbeatTracker::new(Duration::from_secs(5));
+        let now = Instant::now();
+
+        tracker.sent_at(Heartbeat::new(1), now);
+        tracker.sent_at(Heartbeat::new(2), now + Duration::from_millis(10));
+
+        let ack = HeartbeatAck { nonce: 2 };
+        let latency = tracker.acknowledge_at(ack, now + Duration::from_millis(50));
+
+        assert_eq!(latency, Ok(Duration::from_millis(40)));
+        assert_eq!(tracker.pending(), 0);
+    }
+
+    #[test]
+    fn unmatched_ack_returns_error() {
+        let mut tracker = HeartbeatTracker::new(Duration::from_secs(5));
+        let now = Instant::now();
+
+        tracker.sent_at(Heartbeat::new(1), now);
+
+        let ack = HeartbeatAck { nonce: 3 };
+
+        assert_eq!(
+            tracker.acknowledge_at(ack, now),
+            Err(HeartbeatAckError::Mismatch(3))
+        );
+        assert_eq!(tracker.pending(), 1);
+    }
+
+    #[test]
+    fn expired_ack_returns_error() {
+        let mut tracker = HeartbeatTracker::new(Duration::from_secs(5));
+        let now = Instant::now();
+
+        tracker.sent_at(Heartbeat::new(1), now);
+
+        let ack = HeartbeatAck { nonce: 1 };
+        let result = tracker.acknowledge_at(ack, now + Duration::from_secs(6));
+
+        assert_eq!(
+            result,
+            Err(HeartbeatAckError::Expired(1, Duration::from_secs(6)))
+        );
+        assert_eq!(
+            tracker.acknowledge_at(ack, now),
+            Err(HeartbeatAckError::Mismatch(1))
+        );
+    }
+
+    #[test]
+    fn oldest_pending_is_forgotten() {
+        let mut tracker = HeartbeatTracker::new(Duration::from_secs(5));
+        let now = Instant::now();
+
+        for nonce in 0..=MAX_PENDING as u64 {
+            tracker.sent_at(Heartbeat::new(nonce), now);
+        }
+
+        let ack = HeartbeatAck { nonce: 0 };
+
+        assert_eq!(tracker.pending(), MAX_PENDING);
+        assert_eq!(
+            tracker.acknowledge_at(ack, now),
+            Err(HeartbeatAckError::Mismatch(0))
+        );
+    }
+}
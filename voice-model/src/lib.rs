@@ -5,16 +5,18 @@
 mod close_code;
 pub mod constants;
 mod event;
+mod heartbeat_tracker;
 pub mod id;
 mod opcode;
 pub mod payload;
 mod protocol_data;
 mod speaking_state;
-mod util;
+pub mod util;
 
 pub use self::{
     close_code::CloseCode,
     event::Event,
+    heartbeat_tracker::{HeartbeatAckError, HeartbeatTracker},
     opcode::OpCode,
     protocol_data::ProtocolData,
     speaking_state::SpeakingState,
@@ -9,3 +9,15 @@ pub struct Heartbeat {
     #[serde(with = "json_safe_u64")]
     pub nonce: u64,
 }
+
+impl Heartbeat {
+    /// Creates a heartbeat carrying the given nonce.
+    pub fn new(nonce: u64) -> Self {
+        Self { nonce }
+    }
+
+    /// Creates a heartbeat carrying a randomly generated nonce.
+    pub fn random() -> Self {
+        Self::new(rand::random())
+    }
+}
@@ -1,4 +1,13 @@
-pub(crate) mod json_safe_u64 {
+//! Helpers for (de)serializing voice gateway payloads.
+
+/// (De)serialization of `u64`s which may exceed the range of integers that
+/// JSON consumers can represent exactly (2^53).
+///
+/// Use with `#[serde(with = "serenity_voice_model::util::json_safe_u64")]`.
+/// Values are always serialized as strings, such as `"9007199254740993"`,
+/// while both strings and numbers are accepted when deserializing, matching
+/// the payloads Discord sends and accepts.
+pub mod json_safe_u64 {
     use core::fmt::{Formatter, Result as FmtResult};
     use serde::{
         de::{Deserializer, Error, Visitor},
@@ -23,6 +32,7 @@ pub(crate) mod json_safe_u64 {
         }
     }
 
+    /// Deserializes a `u64` from either a string or a number.
     pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
     where
         D: Deserializer<'de>,
@@ -30,6 +40,7 @@ pub(crate) mod json_safe_u64 {
         deserializer.deserialize_any(U64Visitor)
     }
 
+    /// Serializes a `u64` as a string.
     pub fn serialize<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -37,3 +48,28 @@ pub(crate) mod json_safe_u64 {
         serializer.collect_str(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Payload {
+        #[serde(with = "super::json_safe_u64")]
+        value: u64,
+    }
+
+    #[test]
+    fn json_safe_u64_round_trip() {
+        let payload = Payload {
+            value: (1 << 53) + 1,
+        };
+
+        let json = serde_json::to_string(&payload).unwrap();
+        assert_eq!(json, r#"{"value":"9007199254740993"}"#);
+        assert_eq!(serde_json::from_str::<Payload>(&json).unwrap(), payload);
+
+        let json = r#"{"value":9007199254740993}"#;
+        assert_eq!(serde_json::from_str::<Payload>(json).unwrap(), payload);
+    }
+}